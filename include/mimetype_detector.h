@@ -0,0 +1,60 @@
+/*
+ * mimetype-detector C API
+ *
+ * Build the library with the `capi` feature, e.g.:
+ *     cargo rustc --release --features capi --crate-type staticlib
+ *
+ * Formats are identified by their stable numeric id. Ids never change
+ * between releases and can be stored. Types registered at runtime with
+ * register_refinement() have no id and are reported as MTD_ERROR. All
+ * returned strings are static, NUL-terminated and must not be freed.
+ */
+#ifndef MIMETYPE_DETECTOR_H
+#define MIMETYPE_DETECTOR_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returned by the detection functions on invalid arguments, I/O errors or
+ * types without an id (runtime refinements) */
+#define MTD_ERROR (-1)
+
+/* MimeKind bitmask values returned by mtd_kind() */
+#define MTD_KIND_ARCHIVE      (1u << 0)
+#define MTD_KIND_VIDEO        (1u << 1)
+#define MTD_KIND_AUDIO        (1u << 2)
+#define MTD_KIND_IMAGE        (1u << 3)
+#define MTD_KIND_DOCUMENT     (1u << 4)
+#define MTD_KIND_TEXT         (1u << 5)
+#define MTD_KIND_FONT         (1u << 6)
+#define MTD_KIND_EXECUTABLE   (1u << 7)
+#define MTD_KIND_APPLICATION  (1u << 8)
+#define MTD_KIND_MODEL        (1u << 9)
+#define MTD_KIND_DATABASE     (1u << 10)
+#define MTD_KIND_SPREADSHEET  (1u << 11)
+#define MTD_KIND_PRESENTATION (1u << 12)
+
+/* Detects the format of `len` bytes at `data`. Returns a format id or MTD_ERROR. */
+int32_t mtd_detect(const uint8_t *data, size_t len);
+
+/* Detects the format of the file at `path` (UTF-8). Returns a format id or MTD_ERROR. */
+int32_t mtd_detect_file(const char *path);
+
+/* MIME type string for a format id (e.g. "image/png"), or NULL for unknown ids. */
+const char *mtd_mime_name(int32_t id);
+
+/* Primary extension for a format id (e.g. ".png", may be ""), or NULL for unknown ids. */
+const char *mtd_extension(int32_t id);
+
+/* MimeKind bitmask for a format id, or 0 for unknown ids. */
+uint32_t mtd_kind(int32_t id);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MIMETYPE_DETECTOR_H */
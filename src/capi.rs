@@ -0,0 +1,128 @@
+//! C API for embedding the detector in non-Rust code
+//!
+//! Enabled with the `capi` feature. Formats are exchanged as their stable
+//! numeric id (see [`MimeType::id`]); string accessors return pointers to
+//! NUL-terminated static strings that remain valid for the whole program and
+//! must not be freed. No function panics across the FFI boundary: any
+//! internal panic is reported as the documented error value instead.
+//!
+//! Types added at runtime with [`register_refinement`](crate::register_refinement)
+//! have no id, so detecting one of them yields `MTD_ERROR`.
+//!
+//! The matching header lives in `include/mimetype_detector.h`. To produce a
+//! static library for linking from C/C++:
+//!
+//! ```text
+//! cargo rustc --release --features capi --crate-type staticlib
+//! ```
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::catch_unwind;
+use std::sync::LazyLock;
+
+use crate::MimeType;
+
+/// Returned by the detection functions on invalid arguments, I/O errors, panics,
+/// or a detected type without an id (a runtime refinement)
+pub const MTD_ERROR: i32 = -1;
+
+/// NUL-terminated copies of the per-format strings, indexed by id
+struct CStrings {
+    mime: Vec<CString>,
+    extension: Vec<CString>,
+}
+
+static C_STRINGS: LazyLock<CStrings> = LazyLock::new(|| {
+    let types: Vec<&'static MimeType> = (0..=u16::MAX).map_while(MimeType::from_id).collect();
+    let to_c = |s: &str| CString::new(s).unwrap_or_default();
+    CStrings {
+        mime: types.iter().map(|m| to_c(m.mime())).collect(),
+        extension: types.iter().map(|m| to_c(m.extension())).collect(),
+    }
+});
+
+fn to_id(mime: &'static MimeType) -> i32 {
+    mime.id().map_or(MTD_ERROR, i32::from)
+}
+
+fn lookup(strings: &'static [CString], id: i32) -> *const c_char {
+    usize::try_from(id)
+        .ok()
+        .and_then(|index| strings.get(index))
+        .map_or(std::ptr::null(), |s| s.as_ptr())
+}
+
+/// Detects the format of `len` bytes at `data`.
+///
+/// # Returns
+///
+/// The stable format id, or `MTD_ERROR` if `data` is NULL while `len` is non-zero
+/// or the data matched a refinement registered at runtime (which has no id)
+///
+/// # Safety
+///
+/// `data` must point to at least `len` readable bytes (or be NULL when `len` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn mtd_detect(data: *const u8, len: usize) -> i32 {
+    if data.is_null() && len != 0 {
+        return MTD_ERROR;
+    }
+    let input: &[u8] = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: the caller guarantees `data` points to `len` readable bytes
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+    catch_unwind(|| to_id(crate::detect(input))).unwrap_or(MTD_ERROR)
+}
+
+/// Detects the format of the file at `path` (a NUL-terminated UTF-8 string).
+///
+/// # Returns
+///
+/// The stable format id, or `MTD_ERROR` if the path is NULL, not valid UTF-8,
+/// the file cannot be read, or it matched a refinement registered at runtime
+///
+/// # Safety
+///
+/// `path` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mtd_detect_file(path: *const c_char) -> i32 {
+    if path.is_null() {
+        return MTD_ERROR;
+    }
+    // SAFETY: the caller guarantees `path` is NUL-terminated
+    let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return MTD_ERROR;
+    };
+    catch_unwind(|| match crate::detect_file(path) {
+        Ok(mime) => to_id(mime),
+        Err(_) => MTD_ERROR,
+    })
+    .unwrap_or(MTD_ERROR)
+}
+
+/// Returns the MIME type string (e.g. `"image/png"`) for a format id, or NULL for unknown ids.
+#[no_mangle]
+pub extern "C" fn mtd_mime_name(id: i32) -> *const c_char {
+    catch_unwind(|| lookup(&C_STRINGS.mime, id)).unwrap_or(std::ptr::null())
+}
+
+/// Returns the primary file extension (e.g. `".png"`, possibly empty) for a format id,
+/// or NULL for unknown ids.
+#[no_mangle]
+pub extern "C" fn mtd_extension(id: i32) -> *const c_char {
+    catch_unwind(|| lookup(&C_STRINGS.extension, id)).unwrap_or(std::ptr::null())
+}
+
+/// Returns the `MimeKind` bitmask (including parent kinds) for a format id, or 0 for unknown ids.
+#[no_mangle]
+pub extern "C" fn mtd_kind(id: i32) -> u32 {
+    catch_unwind(|| {
+        u16::try_from(id)
+            .ok()
+            .and_then(MimeType::from_id)
+            .map_or(0, |mime| mime.kind().bits())
+    })
+    .unwrap_or(0)
+}
@@ -104,6 +104,12 @@ pub const APPLICATION_X_CFB: &str = "application/x-cfb";
 /// Advanced Authoring Format
 pub const APPLICATION_X_AAF: &str = APPLICATION_OCTET_STREAM;
 
+/// Hangul Word Processor Document
+pub const APPLICATION_X_HWP: &str = "application/x-hwp";
+
+/// Hangul Word Processor OWPML Document
+pub const APPLICATION_HWP_ZIP: &str = "application/hwp+zip";
+
 // ============================================================================
 // ARCHIVE & COMPRESSION FORMATS
 // ============================================================================
@@ -122,6 +128,12 @@ pub const APPLICATION_X_ZIP_COMPRESSED: &str = "application/x-zip-compressed";
 pub const APPLICATION_X_RAR_COMPRESSED: &str = "application/x-rar-compressed";
 pub const APPLICATION_X_RAR: &str = "application/x-rar";
 
+/// Comic book ZIP archive
+pub const APPLICATION_VND_COMICBOOK_ZIP: &str = "application/vnd.comicbook+zip";
+
+/// Comic book RAR archive
+pub const APPLICATION_VND_COMICBOOK_RAR: &str = "application/vnd.comicbook-rar";
+
 /// Par2 (Parchive 2) recovery file
 pub const APPLICATION_X_PAR2: &str = "application/x-par2";
 
@@ -206,6 +218,9 @@ pub const APPLICATION_VND_DEBIAN_BINARY_PACKAGE: &str = "application/vnd.debian.
 /// Web ARChive format
 pub const APPLICATION_WARC: &str = "application/warc";
 
+/// ZIM offline content archive (openZIM / Kiwix)
+pub const APPLICATION_X_ZIM: &str = "application/x-zim";
+
 /// ACE Archive
 pub const APPLICATION_X_ACE_COMPRESSED: &str = "application/x-ace-compressed";
 
@@ -258,6 +273,18 @@ pub const VIDEO_JPM: &str = "video/jpm";
 /// JPEG 2000 Codestream (raw codestream without container)
 pub const IMAGE_X_JP2_CODESTREAM: &str = "image/x-jp2-codestream";
 
+/// High-throughput JPEG 2000 (HTJ2K) image
+pub const IMAGE_JPH: &str = "image/jph";
+
+/// High-throughput JPEG 2000 (HTJ2K) codestream
+pub const IMAGE_JPHC: &str = "image/jphc";
+
+/// National Imagery Transmission Format
+pub const IMAGE_NITF: &str = "image/nitf";
+
+/// JBIG2 bi-level image
+pub const IMAGE_X_JBIG2: &str = "image/x-jbig2";
+
 /// JPEG XS
 pub const IMAGE_JXS: &str = "image/jxs";
 
@@ -325,6 +352,15 @@ pub const IMAGE_X_GIMP_GBR: &str = "image/x-gimp-gbr";
 /// OpenRaster layered image format
 pub const IMAGE_OPENRASTER: &str = "image/openraster";
 
+/// Krita painting document
+pub const APPLICATION_X_KRITA: &str = "application/x-krita";
+
+/// Procreate painting document
+pub const APPLICATION_X_PROCREATE: &str = "application/x-procreate";
+
+/// Affinity Designer/Photo/Publisher document
+pub const APPLICATION_X_AFFINITY: &str = "application/x-affinity";
+
 /// Radiance HDR
 pub const IMAGE_VND_RADIANCE: &str = "image/vnd.radiance";
 
@@ -417,6 +453,18 @@ pub const IMAGE_X_TGA: &str = "image/x-tga";
 /// Sun Raster
 pub const IMAGE_X_SUN_RASTER: &str = "image/x-sun-raster";
 
+/// Kodak Photo CD image pack
+pub const IMAGE_X_PHOTO_CD: &str = "image/x-photo-cd";
+
+/// Wireless Bitmap (WAP)
+pub const IMAGE_VND_WAP_WBMP: &str = "image/vnd.wap.wbmp";
+
+/// X Window System window dump
+pub const IMAGE_X_XWINDOWDUMP: &str = "image/x-xwindowdump";
+
+/// Gzip-compressed SVG
+pub const IMAGE_SVG_XML_COMPRESSED: &str = "image/svg+xml-compressed";
+
 /// Silicon Graphics Image
 pub const IMAGE_X_SGI: &str = "image/x-sgi";
 
@@ -519,6 +567,9 @@ pub const AUDIO_AMR: &str = "audio/amr";
 /// Adaptive Multi-Rate (alias)
 pub const AUDIO_AMR_NB: &str = "audio/amr-nb";
 
+/// Adaptive Multi-Rate Wideband
+pub const AUDIO_AMR_WB: &str = "audio/amr-wb";
+
 /// Creative Voice File
 pub const AUDIO_X_UNKNOWN: &str = "audio/x-unknown";
 
@@ -539,12 +590,27 @@ pub const AUDIO_MP4: &str = "audio/mp4";
 pub const AUDIO_X_M4A: &str = "audio/x-m4a";
 pub const AUDIO_X_MP4A: &str = "audio/x-mp4a";
 
+/// Apple Lossless Audio Codec in an MPEG-4 container
+pub const AUDIO_X_ALAC: &str = "audio/x-alac";
+
 /// WavPack Lossless Audio
 pub const AUDIO_X_WAVPACK: &str = "audio/x-wavpack";
 
 /// True Audio Lossless
 pub const AUDIO_X_TTA: &str = "audio/x-tta";
 
+/// Core Audio Format (Apple)
+pub const AUDIO_X_CAF: &str = "audio/x-caf";
+
+/// Tom's lossless Audio Kompressor
+pub const AUDIO_X_TAK: &str = "audio/x-tak";
+
+/// Shorten lossless audio
+pub const AUDIO_X_SHORTEN: &str = "audio/x-shorten";
+
+/// OptimFROG lossless audio
+pub const AUDIO_X_OPTIMFROG: &str = "audio/x-optimfrog";
+
 /// DSD Stream File
 pub const AUDIO_X_DSF: &str = "audio/x-dsf";
 
@@ -571,6 +637,9 @@ pub const AUDIO_WEBM: &str = "audio/webm";
 /// Matroska video
 pub const VIDEO_X_MATROSKA: &str = "video/x-matroska";
 
+/// Matroska audio
+pub const AUDIO_X_MATROSKA: &str = "audio/x-matroska";
+
 /// Audio Video Interleave
 pub const VIDEO_X_MSVIDEO: &str = "video/x-msvideo";
 pub const VIDEO_AVI: &str = "video/avi";
@@ -579,6 +648,18 @@ pub const VIDEO_MSVIDEO: &str = "video/msvideo";
 /// MPEG video
 pub const VIDEO_MPEG: &str = "video/mpeg";
 
+/// H.264/AVC Annex-B elementary stream
+pub const VIDEO_H264: &str = "video/h264";
+
+/// H.265/HEVC Annex-B elementary stream
+pub const VIDEO_H265: &str = "video/h265";
+
+/// IVF container (VP8/VP9/AV1 test vectors)
+pub const VIDEO_X_IVF: &str = "video/x-ivf";
+
+/// YUV4MPEG2 raw video
+pub const VIDEO_X_YUV4MPEG: &str = "video/x-yuv4mpeg";
+
 /// QuickTime movie
 pub const VIDEO_QUICKTIME: &str = "video/quicktime";
 
@@ -654,6 +735,12 @@ pub const VIDEO_VND_FVT: &str = "video/vnd.fvt";
 /// Material Exchange Format (professional video)
 pub const APPLICATION_MXF: &str = "application/mxf";
 
+/// RED digital cinema camera raw video
+pub const VIDEO_X_RED_R3D: &str = "video/x-red-r3d";
+
+/// Blackmagic RAW video
+pub const VIDEO_X_BRAW: &str = "video/x-braw";
+
 /// MPEG-2 Program Stream
 pub const VIDEO_MP2P: &str = "video/mp2p";
 
@@ -668,6 +755,24 @@ pub const VIDEO_X_WTV: &str = "video/x-wtv";
 pub const APPLICATION_VND_MICROSOFT_PORTABLE_EXECUTABLE: &str =
     "application/vnd.microsoft.portable-executable";
 
+/// Nullsoft Scriptable Install System installer
+pub const APPLICATION_X_NSIS_INSTALLER: &str = "application/x-nsis-installer";
+
+/// Inno Setup installer
+pub const APPLICATION_X_INNOSETUP: &str = "application/x-innosetup";
+
+/// .NET (CLR) managed assembly
+pub const APPLICATION_X_DOTNET_ASSEMBLY: &str = "application/x-dotnet-assembly";
+
+/// Windows dynamic-link library
+pub const APPLICATION_X_MS_DLL: &str = "application/x-ms-dll";
+
+/// Windows kernel-mode driver (native subsystem)
+pub const APPLICATION_X_MS_DRIVER: &str = "application/x-ms-driver";
+
+/// UEFI application or driver
+pub const APPLICATION_EFI: &str = "application/efi";
+
 /// Executable and Linkable Format
 pub const APPLICATION_X_ELF: &str = "application/x-elf";
 
@@ -699,6 +804,27 @@ pub const APPLICATION_VND_APACHE_ARROW_FILE: &str = "application/vnd.apache.arro
 /// Apache Avro
 pub const APPLICATION_VND_APACHE_AVRO: &str = "application/vnd.apache.avro";
 
+/// Apache ORC Columnar Format
+pub const APPLICATION_X_ORC: &str = "application/x-orc";
+
+/// Apache Arrow Feather v1
+pub const APPLICATION_VND_APACHE_ARROW_FEATHER: &str = "application/vnd.apache.arrow.feather";
+
+/// NumPy Array
+pub const APPLICATION_X_NUMPY_DATA: &str = "application/x-numpy-data";
+
+/// NumPy Array Archive
+pub const APPLICATION_X_NUMPY_ARCHIVE: &str = "application/x-numpy-archive";
+
+/// GGUF Machine Learning Model
+pub const APPLICATION_X_GGUF: &str = "application/x-gguf";
+
+/// SafeTensors Machine Learning Model
+pub const APPLICATION_X_SAFETENSORS: &str = "application/x-safetensors";
+
+/// ONNX Machine Learning Model
+pub const APPLICATION_X_ONNX: &str = "application/x-onnx";
+
 /// ID3v2 Audio Metadata
 pub const APPLICATION_X_ID3V2: &str = "application/x-id3v2";
 
@@ -708,9 +834,24 @@ pub const APPLICATION_VND_ICCPROFILE: &str = "application/vnd.iccprofile";
 /// PEM Certificate/Key File
 pub const APPLICATION_X_PEM_FILE: &str = "application/x-pem-file";
 
+/// OpenSSH Private Key
+pub const APPLICATION_X_OPENSSH_PRIVATE_KEY: &str = "application/x-openssh-private-key";
+
+/// SSH Public Key
+pub const APPLICATION_X_SSH_PUBLIC_KEY: &str = "application/x-ssh-public-key";
+
+/// PuTTY Private Key
+pub const APPLICATION_X_PUTTY_PRIVATE_KEY: &str = "application/x-putty-private-key";
+
 /// Age Encryption
 pub const APPLICATION_X_AGE_ENCRYPTION: &str = "application/x-age-encryption";
 
+/// KeePass 2 Database
+pub const APPLICATION_X_KEEPASS2: &str = "application/x-keepass2";
+
+/// LUKS Encrypted Volume
+pub const APPLICATION_X_LUKS: &str = "application/x-luks";
+
 /// Extensible Binary Meta Language
 pub const APPLICATION_X_EBML: &str = "application/x-ebml";
 
@@ -745,6 +886,18 @@ pub const APPLICATION_X_ANGELCODE_BMFONT: &str = "application/x-angelcode-bmfont
 /// Glyphs font editor format
 pub const FONT_X_GLYPHS: &str = "font/x-glyphs";
 
+/// PostScript Type 1 font (PFA/PFB)
+pub const APPLICATION_X_FONT_TYPE1: &str = "application/x-font-type1";
+
+/// Glyph Bitmap Distribution Format font
+pub const APPLICATION_X_FONT_BDF: &str = "application/x-font-bdf";
+
+/// X11 Portable Compiled Format font
+pub const APPLICATION_X_FONT_PCF: &str = "application/x-font-pcf";
+
+/// Linux console PC Screen Font
+pub const APPLICATION_X_FONT_LINUX_PSF: &str = "application/x-font-linux-psf";
+
 // ============================================================================
 // WEB & MULTIMEDIA FORMATS
 // ============================================================================
@@ -768,6 +921,9 @@ pub const APPLICATION_DICOM: &str = "application/dicom";
 /// Mobipocket eBook
 pub const APPLICATION_X_MOBIPOCKET_EBOOK: &str = "application/x-mobipocket-ebook";
 
+/// Kindle Format 8 eBook (AZW3)
+pub const APPLICATION_VND_AMAZON_MOBI8_EBOOK: &str = "application/vnd.amazon.mobi8-ebook";
+
 /// Fasoo document protection
 pub const APPLICATION_X_FASOO: &str = "application/x-fasoo";
 
@@ -882,6 +1038,15 @@ pub const APPLICATION_VND_MS_OUTLOOK: &str = "application/vnd.ms-outlook";
 /// Microsoft Outlook Personal Storage Table
 pub const APPLICATION_VND_MS_OUTLOOK_PST: &str = "application/vnd.ms-outlook";
 
+/// Microsoft Outlook Offline Storage Table
+pub const APPLICATION_VND_MS_OUTLOOK_OST: &str = "application/vnd.ms-outlook-ost";
+
+/// Outlook Express mail store
+pub const APPLICATION_X_DBX: &str = "application/x-dbx";
+
+/// Mozilla Mork database (Thunderbird mail summary files)
+pub const APPLICATION_X_MORK: &str = "application/x-mork";
+
 /// Microsoft Project Plan
 pub const APPLICATION_VND_MS_PROJECT: &str = "application/vnd.ms-project";
 
@@ -991,6 +1156,18 @@ pub const APPLICATION_VND_OASIS_OPENDOCUMENT_GRAPHICS_TEMPLATE: &str =
 pub const APPLICATION_VND_OASIS_OPENDOCUMENT_TEXT_MASTER_TEMPLATE: &str =
     "application/vnd.oasis.opendocument.text-master-template";
 
+/// Flat XML OpenDocument Text
+pub const APPLICATION_VND_OASIS_OPENDOCUMENT_TEXT_FLAT_XML: &str =
+    "application/vnd.oasis.opendocument.text-flat-xml";
+
+/// Flat XML OpenDocument Spreadsheet
+pub const APPLICATION_VND_OASIS_OPENDOCUMENT_SPREADSHEET_FLAT_XML: &str =
+    "application/vnd.oasis.opendocument.spreadsheet-flat-xml";
+
+/// Flat XML OpenDocument Presentation
+pub const APPLICATION_VND_OASIS_OPENDOCUMENT_PRESENTATION_FLAT_XML: &str =
+    "application/vnd.oasis.opendocument.presentation-flat-xml";
+
 // ============================================================================
 // DATABASE FORMATS
 // ============================================================================
@@ -1016,6 +1193,30 @@ pub const APPLICATION_VND_SQLITE3: &str = "application/vnd.sqlite3";
 /// SQLite database (alias)
 pub const APPLICATION_X_SQLITE3: &str = "application/x-sqlite3";
 
+/// SQLite write-ahead log
+pub const APPLICATION_VND_SQLITE3_WAL: &str = "application/vnd.sqlite3-wal";
+
+/// SQLite rollback journal
+pub const APPLICATION_VND_SQLITE3_JOURNAL: &str = "application/vnd.sqlite3-journal";
+
+/// SQLite write-ahead log index (shared memory)
+pub const APPLICATION_VND_SQLITE3_SHM: &str = "application/vnd.sqlite3-shm";
+
+/// OGC GeoPackage (SQLite-based)
+pub const APPLICATION_GEOPACKAGE_SQLITE3: &str = "application/geopackage+sqlite3";
+
+/// MBTiles map tile set (SQLite-based)
+pub const APPLICATION_VND_MBTILES: &str = "application/vnd.mapbox-vector-tile+sqlite3";
+
+/// LevelDB / RocksDB sorted string table
+pub const APPLICATION_X_LEVELDB_SST: &str = "application/x-leveldb-sst";
+
+/// Lightning Memory-Mapped Database
+pub const APPLICATION_X_LMDB: &str = "application/x-lmdb";
+
+/// DuckDB database
+pub const APPLICATION_X_DUCKDB: &str = "application/x-duckdb";
+
 // ============================================================================
 // PROGRAMMING LANGUAGES
 // ============================================================================
@@ -1097,6 +1298,63 @@ pub const APPLICATION_X_SH: &str = "application/x-sh";
 /// Visual Basic Source Code
 pub const TEXT_X_VB: &str = "text/x-vb";
 
+/// Kotlin Source Code
+pub const TEXT_X_KOTLIN: &str = "text/x-kotlin";
+
+/// Swift Source Code
+pub const TEXT_X_SWIFT: &str = "text/x-swift";
+
+/// Dart Source Code
+pub const TEXT_X_DART: &str = "text/x-dart";
+
+/// Objective-C Source Code
+pub const TEXT_X_OBJECTIVE_C: &str = "text/x-objective-c";
+
+/// Scala Source Code
+pub const TEXT_X_SCALA: &str = "text/x-scala";
+
+/// Haskell Source Code
+pub const TEXT_X_HASKELL: &str = "text/x-haskell";
+
+/// Elixir Source Code
+pub const TEXT_X_ELIXIR: &str = "text/x-elixir";
+
+/// Erlang Source Code
+pub const TEXT_X_ERLANG: &str = "text/x-erlang";
+
+/// R Script
+pub const TEXT_X_R: &str = "text/x-r";
+
+/// Julia Source Code
+pub const TEXT_X_JULIA: &str = "text/x-julia";
+
+/// MATLAB Script
+pub const TEXT_X_MATLAB: &str = "text/x-matlab";
+
+/// PowerShell Script
+pub const APPLICATION_X_POWERSHELL: &str = "application/x-powershell";
+
+/// Raku Source Code
+pub const TEXT_X_RAKU: &str = "text/x-raku";
+
+/// Dockerfile
+pub const TEXT_X_DOCKERFILE: &str = "text/x-dockerfile";
+
+/// Makefile
+pub const TEXT_X_MAKEFILE: &str = "text/x-makefile";
+
+/// Diff / Patch File
+pub const TEXT_X_DIFF: &str = "text/x-diff";
+
+/// Patch File (alias)
+pub const TEXT_X_PATCH: &str = "text/x-patch";
+
+/// GraphQL Schema / Query
+pub const APPLICATION_GRAPHQL: &str = "application/graphql";
+
+/// Protocol Buffers Schema
+pub const TEXT_X_PROTOBUF: &str = "text/x-protobuf";
+
 /// LaTeX Document
 pub const TEXT_X_TEX: &str = "text/x-tex";
 
@@ -1113,9 +1371,21 @@ pub const APPLICATION_JSON_UTF16: &str = "application/json; charset=utf-16";
 /// GeoJSON Geographic Data
 pub const APPLICATION_GEO_JSON: &str = "application/geo+json";
 
+/// TopoJSON Topology Data
+pub const APPLICATION_TOPO_JSON: &str = "application/topo+json";
+
 /// Newline Delimited JSON
 pub const APPLICATION_X_NDJSON: &str = "application/x-ndjson";
 
+/// Jupyter Notebook
+pub const APPLICATION_X_IPYNB_JSON: &str = "application/x-ipynb+json";
+
+/// OpenAPI / Swagger Document (JSON)
+pub const APPLICATION_VND_OAI_OPENAPI_JSON: &str = "application/vnd.oai.openapi+json";
+
+/// OpenAPI / Swagger Document (YAML)
+pub const APPLICATION_VND_OAI_OPENAPI: &str = "application/vnd.oai.openapi";
+
 /// CSV Data
 pub const TEXT_CSV: &str = "text/csv";
 
@@ -1144,6 +1414,48 @@ pub const TEXT_SEMICOLON_SEPARATED_VALUES_UTF16: &str =
 /// TOML Configuration File
 pub const APPLICATION_TOML: &str = "application/toml";
 
+/// Cascading Style Sheets
+pub const TEXT_CSS: &str = "text/css";
+
+/// INI Configuration File
+pub const TEXT_X_INI: &str = "text/x-ini";
+
+/// Desktop Entry
+pub const APPLICATION_X_DESKTOP: &str = "application/x-desktop";
+
+/// systemd Unit File
+pub const TEXT_X_SYSTEMD_UNIT: &str = "text/x-systemd-unit";
+
+/// SQL Script
+pub const APPLICATION_SQL: &str = "application/sql";
+
+/// Markdown Document
+pub const TEXT_MARKDOWN: &str = "text/markdown";
+
+/// Markdown Document (alias)
+pub const TEXT_X_MARKDOWN: &str = "text/x-markdown";
+
+/// YAML Document
+pub const APPLICATION_YAML: &str = "application/yaml";
+
+/// YAML Document (alias)
+pub const APPLICATION_X_YAML: &str = "application/x-yaml";
+
+/// YAML Document (alias)
+pub const TEXT_YAML: &str = "text/yaml";
+
+/// YAML Document (alias)
+pub const TEXT_X_YAML: &str = "text/x-yaml";
+
+/// HL7 Version 2 Message (ER7 pipe-delimited encoding)
+pub const APPLICATION_HL7_V2_ER7: &str = "application/hl7-v2+er7";
+
+/// ANSI ASC X12 EDI Interchange
+pub const APPLICATION_EDI_X12: &str = "application/EDI-X12";
+
+/// UN/EDIFACT Interchange
+pub const APPLICATION_EDIFACT: &str = "application/EDIFACT";
+
 /// Rich Text Format
 pub const TEXT_RTF: &str = "text/rtf";
 
@@ -1169,12 +1481,30 @@ pub const TEXT_VTT: &str = "text/vtt";
 /// WebVTT Subtitles with UTF-16 encoding
 pub const TEXT_VTT_UTF16: &str = "text/vtt; charset=utf-16";
 
+/// SubStation Alpha / Advanced SubStation Alpha subtitles
+pub const TEXT_X_SSA: &str = "text/x-ssa";
+
+/// LRC synchronized lyrics
+pub const APPLICATION_X_LRC: &str = "application/x-lrc";
+
+/// CD cue sheet
+pub const APPLICATION_X_CUE: &str = "application/x-cue";
+
 /// vCard Contact
 pub const TEXT_VCARD: &str = "text/vcard";
 
 /// vCard Contact with UTF-16 encoding
 pub const TEXT_VCARD_UTF16: &str = "text/vcard; charset=utf-16";
 
+/// vCard 2.1 Contact
+pub const TEXT_VCARD_V2_1: &str = "text/vcard; version=2.1";
+
+/// vCard 3.0 Contact
+pub const TEXT_VCARD_V3: &str = "text/vcard; version=3.0";
+
+/// vCard 4.0 Contact
+pub const TEXT_VCARD_V4: &str = "text/vcard; version=4.0";
+
 /// iCalendar
 pub const TEXT_CALENDAR: &str = "text/calendar";
 
@@ -1299,6 +1629,18 @@ pub const APPLICATION_VND_SHP: &str = "application/vnd.shp";
 /// ESRI Shapefile Index
 pub const APPLICATION_VND_SHX: &str = "application/vnd.shx";
 
+/// ASPRS LAS lidar point cloud
+pub const APPLICATION_VND_LAS: &str = "application/vnd.las";
+
+/// LASzip compressed lidar point cloud
+pub const APPLICATION_VND_LASZIP: &str = "application/vnd.laszip";
+
+/// ASTM E57 3D imaging data
+pub const APPLICATION_X_E57: &str = "application/x-e57";
+
+/// FlatGeobuf vector features
+pub const APPLICATION_FLATGEOBUF: &str = "application/flatgeobuf";
+
 /// glTF Binary
 pub const MODEL_GLTF_BINARY: &str = "model/gltf-binary";
 
@@ -1317,6 +1659,12 @@ pub const MODEL_STL: &str = "model/stl";
 /// STL ASCII variant
 pub const MODEL_X_STL_ASCII: &str = "model/x.stl-ascii";
 
+/// Wavefront OBJ geometry
+pub const MODEL_OBJ: &str = "model/obj";
+
+/// Object File Format (Geomview)
+pub const MODEL_X_OFF: &str = "model/x-off";
+
 /// Autodesk Maya Binary
 pub const APPLICATION_X_MAYA_BINARY: &str = "application/x-maya-binary";
 
@@ -1453,6 +1801,12 @@ pub const MODEL_VND_DWFX_XPS: &str = "model/vnd.dwfx+xps";
 /// FictionBook ZIP (compressed e-book)
 pub const APPLICATION_X_FBZ: &str = "application/x-fbz";
 
+/// FictionBook 3 (ZIP package)
+pub const APPLICATION_X_FB3: &str = "application/x-fb3";
+
+/// DAISY digital talking book
+pub const APPLICATION_X_DAISY: &str = "application/x-daisy";
+
 /// Alembic Animation Format
 pub const APPLICATION_X_ALEMBIC: &str = "application/x-alembic";
 
@@ -1503,6 +1857,18 @@ pub const APPLICATION_X_EROFS: &str = "application/x-erofs";
 /// Parallels Desktop Disk Image
 pub const APPLICATION_X_PARALLELS_HDD: &str = "application/x-parallels-hdd";
 
+/// ext2/ext3/ext4 filesystem image
+pub const APPLICATION_X_EXT_FS_IMAGE: &str = "application/x-ext-fs-image";
+
+/// FAT12/FAT16/FAT32 filesystem image
+pub const APPLICATION_X_FAT_FS_IMAGE: &str = "application/x-fat-fs-image";
+
+/// NTFS filesystem image
+pub const APPLICATION_X_NTFS_FS_IMAGE: &str = "application/x-ntfs-fs-image";
+
+/// GPT-partitioned disk image
+pub const APPLICATION_X_GPT_DISK_IMAGE: &str = "application/x-gpt-disk-image";
+
 // ============================================================================
 // NINTENDO & GAMING FORMATS
 // ============================================================================
@@ -1543,8 +1909,24 @@ pub const APPLICATION_PLY: &str = "application/ply";
 /// CBOR Binary Data
 pub const APPLICATION_CBOR: &str = "application/cbor";
 
+/// MessagePack Binary Data
+pub const APPLICATION_MSGPACK: &str = "application/msgpack";
+
+/// MessagePack Binary Data (alias)
+pub const APPLICATION_X_MSGPACK: &str = "application/x-msgpack";
+
+/// MessagePack Binary Data (alias)
+pub const APPLICATION_VND_MSGPACK: &str = "application/vnd.msgpack";
+
+/// BSON Binary JSON
+pub const APPLICATION_BSON: &str = "application/bson";
+
 /// Brotli Compressed
 pub const APPLICATION_BROTLI: &str = "application/brotli";
+pub const APPLICATION_X_BROTLI: &str = "application/x-brotli";
+
+/// zchunk compressed file
+pub const APPLICATION_X_ZCHUNK: &str = "application/x-zchunk";
 
 /// Network Common Data Form
 pub const APPLICATION_X_NETCDF: &str = "application/x-netcdf";
@@ -1564,6 +1946,18 @@ pub const APPLICATION_X_MS_SHORTCUT: &str = "application/x-ms-shortcut";
 /// Mach-O Binary
 pub const APPLICATION_X_MACH_BINARY: &str = "application/x-mach-binary";
 
+/// Mach-O universal (fat) binary
+pub const APPLICATION_X_MACH_FAT_BINARY: &str = "application/x-mach-fat-binary";
+
+/// Mach-O dynamic library
+pub const APPLICATION_X_MACH_DYLIB: &str = "application/x-mach-dylib";
+
+/// Mach-O executable
+pub const APPLICATION_X_MACH_EXECUTABLE: &str = "application/x-mach-executable";
+
+/// Mach-O relocatable object
+pub const APPLICATION_X_MACH_OBJECT: &str = "application/x-mach-object";
+
 /// Time Zone Information Format
 pub const APPLICATION_TZIF: &str = "application/tzif";
 
@@ -1640,6 +2034,24 @@ pub const APPLICATION_X_GBA_ROM: &str = "application/x-gba-rom";
 /// Nintendo Entertainment System ROM
 pub const APPLICATION_X_NINTENDO_NES_ROM: &str = "application/x-nintendo-nes-rom";
 
+/// Super Nintendo (SNES) ROM
+pub const APPLICATION_X_SNES_ROM: &str = "application/x-snes-rom";
+
+/// Nintendo 3DS ROM (NCSD card image or CIA installable archive)
+pub const APPLICATION_X_NINTENDO_3DS_ROM: &str = "application/x-nintendo-3ds-rom";
+
+/// Nintendo GameCube disc image
+pub const APPLICATION_X_GAMECUBE_ISO: &str = "application/x-gamecube-iso";
+
+/// Nintendo Wii disc image
+pub const APPLICATION_X_WII_ISO: &str = "application/x-wii-iso";
+
+/// Wii Backup File System image
+pub const APPLICATION_X_WBFS: &str = "application/x-wbfs";
+
+/// Dolphin RVZ compressed disc image
+pub const APPLICATION_X_RVZ: &str = "application/x-rvz";
+
 // ============================================================================
 // CERTIFICATE AND KEY FORMATS
 // ============================================================================
@@ -1647,6 +2059,9 @@ pub const APPLICATION_X_NINTENDO_NES_ROM: &str = "application/x-nintendo-nes-rom
 /// DER Certificate
 pub const APPLICATION_X_X509_CA_CERT: &str = "application/x-x509-ca-cert";
 
+/// PKCS#12 Keystore
+pub const APPLICATION_X_PKCS12: &str = "application/x-pkcs12";
+
 /// Java Keystore
 pub const APPLICATION_X_JAVA_KEYSTORE: &str = "application/x-java-keystore";
 
@@ -1737,6 +2152,18 @@ pub const AUDIO_X_S3M: &str = "audio/x-s3m";
 /// ProTracker Module
 pub const AUDIO_X_MOD: &str = "audio/x-mod";
 
+/// Commodore 64 SID tune (PSID/RSID)
+pub const AUDIO_PRS_SID: &str = "audio/prs.sid";
+
+/// NES Sound Format
+pub const AUDIO_X_NSF: &str = "audio/x-nsf";
+
+/// SNES SPC700 sound dump
+pub const AUDIO_X_SPC: &str = "audio/x-spc";
+
+/// Video Game Music register log
+pub const AUDIO_X_VGM: &str = "audio/x-vgm";
+
 // ============================================================================
 // APPLE FORMATS
 // ============================================================================
@@ -1747,6 +2174,15 @@ pub const APPLICATION_X_APPLE_DISKIMAGE: &str = "application/x-apple-diskimage";
 /// macOS Alias File
 pub const APPLICATION_X_APPLE_ALIAS: &str = "application/x-apple-alias";
 
+/// Apple Pages Document
+pub const APPLICATION_VND_APPLE_PAGES: &str = "application/vnd.apple.pages";
+
+/// Apple Numbers Spreadsheet
+pub const APPLICATION_VND_APPLE_NUMBERS: &str = "application/vnd.apple.numbers";
+
+/// Apple Keynote Presentation
+pub const APPLICATION_VND_APPLE_KEYNOTE: &str = "application/vnd.apple.keynote";
+
 // ============================================================================
 // GAME ROM FORMATS (SEGA)
 // ============================================================================
@@ -1865,6 +2301,15 @@ pub const APPLICATION_X_TASTY: &str = "application/x-tasty";
 /// PAK archive format
 pub const APPLICATION_X_PAK: &str = "application/x-pak";
 
+/// Doom engine WAD archive
+pub const APPLICATION_X_DOOM_WAD: &str = "application/x-doom-wad";
+
+/// Valve Pak (Source engine)
+pub const APPLICATION_X_VPK: &str = "application/x-vpk";
+
+/// Godot engine resource pack
+pub const APPLICATION_X_GODOT_PCK: &str = "application/x-godot-pck";
+
 // ============================================================================
 // DATABASE FORMATS
 // ============================================================================
@@ -1938,12 +2383,62 @@ pub const APPLICATION_X_HDF: &str = "application/x-hdf";
 /// HDF5 Hierarchical Data Format
 pub const APPLICATION_X_HDF5: &str = "application/x-hdf5";
 
+/// MINC-2 medical volume (HDF5-based)
+pub const APPLICATION_X_MINC: &str = "application/x-minc";
+
+/// NIfTI neuroimaging volume
+pub const IMAGE_X_NIFTI: &str = "image/x-nifti";
+
+/// Nearly Raw Raster Data
+pub const APPLICATION_X_NRRD: &str = "application/x-nrrd";
+
 /// GRIB weather data format
 pub const APPLICATION_X_GRIB: &str = "application/x-grib";
 
 /// BUFR meteorological data format
 pub const APPLICATION_X_BUFR: &str = "application/x-bufr";
 
+// ============================================================================
+// BIOINFORMATICS FORMATS
+// ============================================================================
+
+/// FASTA nucleotide or protein sequences
+pub const TEXT_X_FASTA: &str = "text/x-fasta";
+
+/// FASTQ sequencing reads with quality scores
+pub const TEXT_X_FASTQ: &str = "text/x-fastq";
+
+/// Sequence Alignment/Map
+pub const TEXT_X_SAM: &str = "text/x-sam";
+
+/// Binary Alignment/Map (BGZF-compressed SAM)
+pub const APPLICATION_X_BAM: &str = "application/x-bam";
+
+/// CRAM reference-compressed alignments
+pub const APPLICATION_CRAM: &str = "application/cram";
+
+/// Variant Call Format
+pub const TEXT_VCF_VARIANT: &str = "text/vcf-variant";
+
+// ============================================================================
+// STATISTICAL DATA FORMATS
+// ============================================================================
+
+/// SPSS system file
+pub const APPLICATION_X_SPSS_SAV: &str = "application/x-spss-sav";
+
+/// SAS data set
+pub const APPLICATION_X_SAS_DATA: &str = "application/x-sas-data";
+
+/// Stata data file
+pub const APPLICATION_X_STATA_DTA: &str = "application/x-stata-dta";
+
+/// R workspace or serialized object
+pub const APPLICATION_X_RDATA: &str = "application/x-rdata";
+
+/// MATLAB MAT-file (Level 5 or HDF5-based v7.3)
+pub const APPLICATION_X_MATLAB_DATA: &str = "application/x-matlab-data";
+
 // ============================================================================
 // CINEMA FORMATS
 // ============================================================================
@@ -1967,6 +2462,15 @@ pub const APPLICATION_X_MS_EVT: &str = "application/x-ms-evt";
 /// Windows Event Log XML
 pub const APPLICATION_X_MS_EVTX: &str = "application/x-ms-evtx";
 
+/// Windows Registry Hive
+pub const APPLICATION_X_MS_REGISTRY_HIVE: &str = "application/x-ms-registry-hive";
+
+/// Windows Prefetch
+pub const APPLICATION_X_MS_PREFETCH: &str = "application/x-ms-prefetch";
+
+/// Windows Memory Dump
+pub const APPLICATION_X_DMP: &str = "application/x-dmp";
+
 /// OS/2 Help file
 pub const APPLICATION_X_OS2_HLP: &str = "application/x-os2-hlp";
 
@@ -2058,3 +2562,6 @@ pub const IMAGE_X_WIN_CUR: &str = "image/x-win-cursor";
 
 /// Email message (RFC822)
 pub const MESSAGE_RFC822: &str = "message/rfc822";
+
+/// Mailbox (mbox)
+pub const APPLICATION_MBOX: &str = "application/mbox";
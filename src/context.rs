@@ -0,0 +1,91 @@
+//! Per-detection scratch state shared between matchers
+//!
+//! Matchers are plain `fn(&[u8]) -> bool`, so sibling matchers cannot pass
+//! intermediate results to each other. While `detect` runs, a thread-local
+//! pass records the input being classified and memoizes derived data (such
+//! as the language token index) so that it is computed once per detection
+//! instead of once per matcher. Outside a pass nothing is cached.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A memoized value and the input slice it was derived from
+struct Memo {
+    type_id: TypeId,
+    start: usize,
+    len: usize,
+    value: Rc<dyn Any>,
+}
+
+/// The input range of the running detection and its memoized values
+struct Pass {
+    start: usize,
+    len: usize,
+    memos: Vec<Memo>,
+}
+
+thread_local! {
+    static PASS: RefCell<Option<Pass>> = const { RefCell::new(None) };
+}
+
+/// Restores the enclosing pass (if any) when a pass ends, even on panic
+struct Restore(Option<Pass>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        PASS.with(|pass| *pass.borrow_mut() = outer);
+    }
+}
+
+/// Runs `detect` as a single detection pass over `input`
+pub(crate) fn with_pass<R>(input: &[u8], detect: impl FnOnce() -> R) -> R {
+    let pass = Pass {
+        start: input.as_ptr() as usize,
+        len: input.len(),
+        memos: Vec::new(),
+    };
+    let _restore = Restore(PASS.with(|current| current.replace(Some(pass))));
+    detect()
+}
+
+/// Returns `build(data)`, computed at most once per pass for the same slice
+///
+/// Values are only shared when `data` lies within the input of the running
+/// pass; the input is borrowed for the whole pass, so its bytes cannot
+/// change between calls. Any other slice (e.g. a temporary decoded buffer)
+/// is built fresh every time.
+pub(crate) fn memoize<T: 'static>(data: &[u8], build: fn(&[u8]) -> T) -> Rc<T> {
+    let type_id = TypeId::of::<T>();
+    let start = data.as_ptr() as usize;
+    let len = data.len();
+
+    let cached = PASS.with(|pass| {
+        let pass = pass.borrow();
+        let pass = pass.as_ref()?;
+        pass.memos
+            .iter()
+            .find(|memo| memo.type_id == type_id && memo.start == start && memo.len == len)
+            .and_then(|memo| Rc::clone(&memo.value).downcast::<T>().ok())
+    });
+    if let Some(value) = cached {
+        return value;
+    }
+
+    let value = Rc::new(build(data));
+    PASS.with(|pass| {
+        if let Some(pass) = pass.borrow_mut().as_mut() {
+            let within = start >= pass.start && start + len <= pass.start + pass.len;
+            if within {
+                pass.memos.push(Memo {
+                    type_id,
+                    start,
+                    len,
+                    value: value.clone(),
+                });
+            }
+        }
+    });
+    value
+}
@@ -0,0 +1,60 @@
+//! Errors reported by the `try_detect_*` APIs
+//!
+//! The plain detection functions always produce a MIME type, falling back to
+//! `application/x-empty` or a generic type when nothing more specific fits.
+//! [`DetectError`] lets callers tell those fallbacks apart from real results.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::MimeType;
+
+/// Reason a `try_detect_*` call produced no specific MIME type
+pub enum DetectError {
+    /// Reading the input failed
+    Io(io::Error),
+    /// The input contained no bytes at all
+    Empty,
+    /// The input ended inside the magic number of the given format
+    TooShortFor(&'static MimeType),
+}
+
+impl fmt::Debug for DetectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectError::Io(error) => f.debug_tuple("Io").field(error).finish(),
+            DetectError::Empty => f.write_str("Empty"),
+            DetectError::TooShortFor(mime) => {
+                f.debug_tuple("TooShortFor").field(&mime.mime()).finish()
+            }
+        }
+    }
+}
+
+impl fmt::Display for DetectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectError::Io(error) => write!(f, "I/O error: {error}"),
+            DetectError::Empty => f.write_str("input is empty"),
+            DetectError::TooShortFor(mime) => {
+                write!(f, "input too short for {} signature", mime.mime())
+            }
+        }
+    }
+}
+
+impl Error for DetectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DetectError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DetectError {
+    fn from(error: io::Error) -> Self {
+        DetectError::Io(error)
+    }
+}
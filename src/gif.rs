@@ -0,0 +1,73 @@
+//! GIF block stream inspection
+//!
+//! Both still and animated GIFs are detected as `image/gif`; this module
+//! tells them apart for callers that need to know which one they have.
+
+/// Reports whether a GIF is animated.
+///
+/// Walks the block stream after the logical screen descriptor. A
+/// NETSCAPE2.0/ANIMEXTS1.0 looping extension or a second image marks the
+/// file as animated; the trailer after at most one image marks it as still.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice starting at the beginning of the GIF file
+///
+/// # Returns
+///
+/// `Some(true)` or `Some(false)` once the answer is known, `None` if `data`
+/// ends (or is malformed) before either is reached
+pub fn gif_is_animated(data: &[u8]) -> Option<bool> {
+    let flags = *data.get(10)?;
+    // Skip the header, logical screen descriptor and global color table
+    let mut pos = 13
+        + if flags & 0x80 != 0 {
+            3 << ((flags & 0x07) + 1)
+        } else {
+            0
+        };
+    let mut images = 0;
+
+    loop {
+        match *data.get(pos)? {
+            0x21 => {
+                let label = *data.get(pos + 1)?;
+                let first = data.get(pos + 2..)?;
+                if label == 0xFF
+                    && (first.starts_with(b"\x0bNETSCAPE2.0")
+                        || first.starts_with(b"\x0bANIMEXTS1.0"))
+                {
+                    return Some(true);
+                }
+                pos = gif_skip_sub_blocks(data, pos + 2)?;
+            }
+            0x2C => {
+                images += 1;
+                if images > 1 {
+                    return Some(true);
+                }
+                let packed = *data.get(pos + 9)?;
+                let local_table = if packed & 0x80 != 0 {
+                    3 << ((packed & 0x07) + 1)
+                } else {
+                    0
+                };
+                // Descriptor, local color table and the LZW minimum code size byte
+                pos = gif_skip_sub_blocks(data, pos + 10 + local_table + 1)?;
+            }
+            0x3B => return Some(false),
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the position after the sub-block chain starting at `pos`
+fn gif_skip_sub_blocks(input: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        let len = usize::from(*input.get(pos)?);
+        pos += 1 + len;
+        if len == 0 {
+            return Some(pos);
+        }
+    }
+}
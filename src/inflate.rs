@@ -0,0 +1,270 @@
+//! Bounded raw DEFLATE decoding for content sniffing
+//!
+//! A few formats only reveal themselves inside a compressed stream: BAM
+//! alignments, for instance, are BGZF (blocked gzip) files whose magic is the
+//! first thing in the deflated payload. Matchers only need the first few
+//! output bytes, so [`inflate_prefix`] decodes until it has produced `limit`
+//! bytes and then stops, keeping the cost proportional to what is inspected.
+
+/// Maximum Huffman code length in DEFLATE
+const MAX_BITS: usize = 15;
+
+/// Base match lengths for length symbols 257..=285
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// Base distances for distance symbols 0..=29
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Order in which code length code lengths are stored in a dynamic block header
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// LSB-first bit reader over a byte slice
+struct Bits<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl Bits<'_> {
+    fn bits(&mut self, count: u8) -> Option<u32> {
+        let mut value = 0;
+        for shift in 0..count {
+            let byte = *self.data.get(self.pos / 8)?;
+            value |= u32::from((byte >> (self.pos % 8)) & 1) << shift;
+            self.pos += 1;
+        }
+        Some(value)
+    }
+
+    fn align(&mut self) {
+        self.pos = self.pos.div_ceil(8) * 8;
+    }
+}
+
+/// Canonical Huffman decoding table (code counts per length, symbols by code)
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    /// Builds a table from per-symbol code lengths; `None` if oversubscribed
+    fn new(lengths: &[u8]) -> Option<Self> {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &len in lengths {
+            counts[usize::from(len)] += 1;
+        }
+        let mut left: i32 = 1;
+        for &count in &counts[1..] {
+            left = (left << 1) - i32::from(count);
+            if left < 0 {
+                return None;
+            }
+        }
+
+        let mut offsets = [0u16; MAX_BITS + 1];
+        for len in 1..MAX_BITS {
+            offsets[len + 1] = offsets[len] + counts[len];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                let slot = &mut offsets[usize::from(len)];
+                symbols[usize::from(*slot)] = symbol as u16;
+                *slot += 1;
+            }
+        }
+        Some(Self { counts, symbols })
+    }
+
+    fn decode(&self, bits: &mut Bits) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= bits.bits(1)? as i32;
+            let count = i32::from(count);
+            if code - first < count {
+                return self.symbols.get((index + code - first) as usize).copied();
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+/// Decodes up to `limit` bytes of a raw DEFLATE stream
+///
+/// Decoding stops early at the end of the stream, on truncated input or on
+/// malformed data; whatever was produced until then is returned.
+pub(crate) fn inflate_prefix(data: &[u8], limit: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(limit);
+    let _ = inflate_into(&mut Bits { data, pos: 0 }, &mut out, limit);
+    out.truncate(limit);
+    out
+}
+
+fn inflate_into(bits: &mut Bits, out: &mut Vec<u8>, limit: usize) -> Option<()> {
+    while out.len() < limit {
+        let last = bits.bits(1)? == 1;
+        match bits.bits(2)? {
+            0 => stored(bits, out, limit)?,
+            1 => {
+                let mut lengths = [0u8; 288 + 30];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..288].fill(8);
+                lengths[288..].fill(5);
+                let literals = Huffman::new(&lengths[..288])?;
+                let distances = Huffman::new(&lengths[288..])?;
+                codes(bits, out, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_tables(bits)?;
+                codes(bits, out, limit, &literals, &distances)?;
+            }
+            _ => return None,
+        }
+        if last {
+            break;
+        }
+    }
+    Some(())
+}
+
+fn stored(bits: &mut Bits, out: &mut Vec<u8>, limit: usize) -> Option<()> {
+    bits.align();
+    let start = bits.pos / 8;
+    let header = bits.data.get(start..start + 4)?;
+    let len = u16::from_le_bytes([header[0], header[1]]);
+    if len != !u16::from_le_bytes([header[2], header[3]]) {
+        return None;
+    }
+    let available = &bits.data[start + 4..];
+    let take = usize::from(len).min(limit - out.len());
+    out.extend_from_slice(available.get(..take).unwrap_or(available));
+    bits.pos = (start + 4 + usize::from(len)) * 8;
+    (available.len() >= take).then_some(())
+}
+
+fn dynamic_tables(bits: &mut Bits) -> Option<(Huffman, Huffman)> {
+    let literal_count = bits.bits(5)? as usize + 257;
+    let distance_count = bits.bits(5)? as usize + 1;
+    let code_length_count = bits.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return None;
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for &slot in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[slot] = bits.bits(3)? as u8;
+    }
+    let code_length_table = Huffman::new(&code_lengths)?;
+
+    let mut lengths = vec![0u8; literal_count + distance_count];
+    let mut index = 0;
+    while index < lengths.len() {
+        let symbol = code_length_table.decode(bits)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => (*lengths.get(index.checked_sub(1)?)?, 3 + bits.bits(2)?),
+            17 => (0, 3 + bits.bits(3)?),
+            _ => (0, 11 + bits.bits(7)?),
+        };
+        let end = index + repeat as usize;
+        lengths.get_mut(index..end)?.fill(value);
+        index = end;
+    }
+    if lengths[256] == 0 {
+        return None;
+    }
+
+    Some((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ))
+}
+
+fn codes(
+    bits: &mut Bits,
+    out: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Option<()> {
+    while out.len() < limit {
+        let symbol = usize::from(literals.decode(bits)?);
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Some(()),
+            _ => {
+                let index = symbol - 257;
+                let len = usize::from(*LENGTH_BASE.get(index)?)
+                    + bits.bits(LENGTH_EXTRA[index])? as usize;
+                let index = usize::from(distances.decode(bits)?);
+                let distance = usize::from(*DISTANCE_BASE.get(index)?)
+                    + bits.bits(DISTANCE_EXTRA[index])? as usize;
+                let start = out.len().checked_sub(distance)?;
+                for offset in 0..len {
+                    out.push(out[start + offset]);
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflate_stored_block() {
+        let data = b"\x01\x08\x00\xf7\xffBAM\x01\x0b\x00\x00\x00";
+        assert_eq!(inflate_prefix(data, 64), b"BAM\x01\x0b\x00\x00\x00");
+        assert_eq!(inflate_prefix(data, 4), b"BAM\x01");
+    }
+
+    #[test]
+    fn test_inflate_fixed_huffman() {
+        // "BAM\x01hello hello hello" with a back reference
+        let data = b"sr\xf4e\xccH\xcd\xc9\xc9W@\"\x01";
+        assert_eq!(inflate_prefix(data, 64), b"BAM\x01hello hello hello");
+    }
+
+    #[test]
+    fn test_inflate_dynamic_huffman() {
+        // zlib level 9 of six "@SQ\tSN:chrN\tLN:..." SAM header lines
+        let data =
+            b"\x4d\xc8\x31\x0e\x80\x30\x08\x05\xd0\xb9\xbd\x0d\x85\x0f\xa5\x53\x0f\x60\x9a\x98\
+\x1e\xc1\xc5\xd9\xfb\x0f\x06\x07\xc3\x1b\xdf\xdc\x67\xd9\x6b\x5c\xf7\x43\xe5\x58\xc3\x9c\
+\xbc\xce\xff\x5a\x1c\xa1\x73\x4f\xc9\x91\x8d\x0d\x96\x52\x22\x99\xd4\x34\x25\xbe\x74\x38\
+\x52\x6a\xa4\x18\x48\xea\x0b";
+        let expected: Vec<u8> = (1..7)
+            .flat_map(|i| format!("@SQ\tSN:chr{i}\tLN:{}\n", i * 7919).into_bytes())
+            .collect();
+        assert_eq!(inflate_prefix(data, 1024), expected);
+        assert_eq!(inflate_prefix(data, 10), &expected[..10]);
+    }
+
+    #[test]
+    fn test_inflate_rejects_garbage() {
+        assert!(inflate_prefix(b"\x07\xff\xff\xff", 16).is_empty());
+        assert!(inflate_prefix(b"", 16).is_empty());
+    }
+}
@@ -8,6 +8,7 @@
 /// A MIME type can belong to multiple categories (e.g., an executable can also be an archive).
 /// Use bitwise operations to combine or check for multiple kinds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MimeKind(u32);
 
 impl MimeKind {
@@ -59,6 +60,12 @@ impl MimeKind {
         (self.0 & other.0) == other.0
     }
 
+    /// Get the raw bitmask value
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
     /// Combine this kind with another using bitwise OR
     #[inline]
     pub const fn union(self, other: MimeKind) -> MimeKind {
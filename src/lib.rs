@@ -9,9 +9,10 @@
 //! - **Fast and precise** MIME type detection using magic number analysis
 //! - **527 supported formats** including images, audio, video, documents, archives, and more
 //! - **Thread-safe** operations with lazy initialization
-//! - **Zero unsafe code** - built with RwLock and LazyLock for safety
-//! - **Memory efficient** - reads only first 3KB of files
-//! - **Zero dependencies** - pure Rust implementation
+//! - **Zero unsafe code** - built with RwLock and LazyLock for safety (outside the optional `capi` FFI layer)
+//! - **Memory efficient** - reads the first 3KB of a file, plus small tail and fixed-offset windows
+//!   when needed (ZIP central directory, OLE root entry, disc image descriptors)
+//! - **Zero required dependencies** - pure Rust implementation (optional `serde` feature)
 //!
 //! ## Quick Start
 //!
@@ -75,9 +76,10 @@
 //! ```
 
 use std::collections::HashMap;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{LazyLock, Once, RwLock};
 
 pub mod mime_type;
@@ -89,9 +91,31 @@ pub use kind::MimeKind;
 pub mod constants;
 pub use constants::*;
 
+pub mod gif;
+pub use gif::gif_is_animated;
+
+pub mod pcap;
+pub use pcap::{pcap_info, Endianness, PcapInfo};
+
+pub mod report;
+pub use report::{detect_report, DetectionReport};
+
+pub mod error;
+pub use error::DetectError;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
 #[macro_use]
 mod macros;
 
+mod context;
+
+mod inflate;
+
+mod source;
+use source::SparseFile;
+
 mod tree;
 use tree::ROOT;
 
@@ -121,7 +145,17 @@ fn ensure_init() {
 ///   instead of specific formats like `application/x-ms-installer`.
 /// - **Sega Game ROM formats** (Game Gear, Master System): Signatures at 8KB-32KB offsets.
 /// - **Large header formats**: Any format with signatures beyond 3KB.
-const READ_LIMIT: usize = 3072;
+///
+/// `detect_file()` additionally probes the known large offsets and the file trailer,
+/// so ISO 9660, UDF, Sega ROM and fixed VHD images are found there regardless of the limit.
+pub(crate) const READ_LIMIT: usize = 3072;
+
+/// First numeric id of the range reserved for application-defined MIME types.
+///
+/// Built-in formats are numbered from 0 upward (see [`MimeType::id`]) and will
+/// never be assigned an id at or above this value, so applications storing ids
+/// can use `CUSTOM_ID_START..=u16::MAX` for their own registered types.
+pub const CUSTOM_ID_START: u16 = 0xF000;
 
 /// Detects the MIME type of the given byte data.
 ///
@@ -159,7 +193,7 @@ pub fn detect_with_limit(data: &[u8], limit: usize) -> &'static MimeType {
     } else {
         data
     };
-    ROOT.match_bytes(input)
+    context::with_pass(input, || tree::match_root(input))
 }
 
 /// Detects the MIME type by reading from a `Read` implementor.
@@ -195,9 +229,60 @@ pub fn detect_reader_with_limit<R: Read>(
     mut reader: R,
     limit: usize,
 ) -> io::Result<&'static MimeType> {
-    let mut buffer = vec![0u8; limit];
-    let n = reader.read(&mut buffer)?;
-    Ok(detect_with_limit(&buffer[..n], limit))
+    let head = read_head(&mut reader, limit)?;
+    Ok(detect_with_limit(&head, limit))
+}
+
+/// Detects the MIME type by reading from a `Read` implementor, reporting fallbacks as errors.
+///
+/// Like [`detect_reader`], but distinguishes an empty stream and input that
+/// ends inside a known magic number from a regular detection result.
+///
+/// # Arguments
+///
+/// * `reader` - Any type implementing the `Read` trait
+///
+/// # Returns
+///
+/// The detected MIME type, or a [`DetectError`] describing why none was determined
+pub fn try_detect_reader<R: Read>(reader: R) -> Result<&'static MimeType, DetectError> {
+    try_detect_reader_with_limit(reader, READ_LIMIT)
+}
+
+/// Like [`try_detect_reader`], reading at most `limit` bytes.
+///
+/// # Arguments
+///
+/// * `reader` - Any type implementing the `Read` trait
+/// * `limit` - Maximum number of bytes to read from the reader
+///
+/// # Returns
+///
+/// The detected MIME type, or a [`DetectError`] describing why none was determined
+pub fn try_detect_reader_with_limit<R: Read>(
+    mut reader: R,
+    limit: usize,
+) -> Result<&'static MimeType, DetectError> {
+    let head = read_head(&mut reader, limit)?;
+    classify(&head, detect_with_limit(&head, limit))
+}
+
+/// Reads up to `limit` bytes, continuing after short reads until EOF
+fn read_head<R: Read>(reader: &mut R, limit: usize) -> io::Result<Vec<u8>> {
+    let mut head = Vec::with_capacity(limit.min(READ_LIMIT));
+    reader.take(limit as u64).read_to_end(&mut head)?;
+    Ok(head)
+}
+
+/// Turns the empty and truncated-signature fallbacks into errors
+fn classify(head: &[u8], detected: &'static MimeType) -> Result<&'static MimeType, DetectError> {
+    if head.is_empty() {
+        return Err(DetectError::Empty);
+    }
+    match tree::truncated_signature(head, detected) {
+        Some(candidate) => Err(DetectError::TooShortFor(candidate)),
+        None => Ok(detected),
+    }
 }
 
 /// Detects the MIME type of a file at the given path.
@@ -218,6 +303,10 @@ pub fn detect_file<P: AsRef<Path>>(path: P) -> io::Result<&'static MimeType> {
 /// Detects the MIME type of a file at the given path with a custom read limit.
 ///
 /// Opens the file and reads up to `limit` bytes to determine the MIME type.
+/// If those bytes are inconclusive, the file is additionally probed at the
+/// large offsets and trailer where formats like ISO 9660, UDF, Sega ROMs and
+/// fixed VHD images keep their signatures, so these are detected regardless
+/// of `limit`.
 ///
 /// # Arguments
 ///
@@ -231,8 +320,103 @@ pub fn detect_file_with_limit<P: AsRef<Path>>(
     path: P,
     limit: usize,
 ) -> io::Result<&'static MimeType> {
-    let file = File::open(path)?;
-    detect_reader_with_limit(file, limit)
+    detect_file_head(path.as_ref(), limit).map(|(detected, _)| detected)
+}
+
+/// Detects the MIME type of a file, reporting fallbacks as errors.
+///
+/// Like [`detect_file`], but distinguishes an empty file and a file that
+/// ends inside a known magic number from a regular detection result.
+///
+/// # Arguments
+///
+/// * `path` - The file system path to the file to analyze (accepts &str, String, Path, PathBuf, etc.)
+///
+/// # Returns
+///
+/// The detected MIME type, or a [`DetectError`] describing why none was determined
+pub fn try_detect_file<P: AsRef<Path>>(path: P) -> Result<&'static MimeType, DetectError> {
+    let (detected, head) = detect_file_head(path.as_ref(), READ_LIMIT)?;
+    classify(&head, detected)
+}
+
+/// Detects a file from its head and, if inconclusive, its probe windows
+///
+/// A plain ZIP head is refined from the entry names in the central directory,
+/// a generic OLE head from the root directory entry.
+///
+/// Returns the detection result together with the head bytes it was based on.
+fn detect_file_head(path: &Path, limit: usize) -> io::Result<(&'static MimeType, Vec<u8>)> {
+    let mut file = File::open(path)?;
+    let head = read_head(&mut file, limit)?;
+
+    let detected = detect_with_limit(&head, limit);
+    if tree::is_refinable_zip(detected) {
+        let source = SparseFile::load(&mut file, head, tree::ZIP_WINDOWS)?;
+        let detected = tree::match_zip_central_directory(&source).unwrap_or(detected);
+        return Ok((detected, source.into_head()));
+    }
+    if let Some(window) = tree::ole_root_entry_window(detected, &head) {
+        let source = SparseFile::load(&mut file, head, &[window])?;
+        let detected = tree::match_ole_root_entry(&source).unwrap_or(detected);
+        return Ok((detected, source.into_head()));
+    }
+    if !std::ptr::eq(detected, &ROOT) {
+        return Ok((detected, head));
+    }
+
+    let source = SparseFile::load(&mut file, head, tree::FILE_WINDOWS)?;
+    let detected = tree::match_file_windows(&source).unwrap_or(detected);
+    Ok((detected, source.into_head()))
+}
+
+/// Result of detecting a single file during a directory scan
+pub type DirEntryResult = (PathBuf, io::Result<&'static MimeType>);
+
+/// Detects the MIME type of every regular file in a directory.
+///
+/// Entries are visited in path order. Symbolic links are never followed,
+/// so they are skipped along with other non-regular entries (sockets, FIFOs,
+/// devices). Subdirectories are descended into only when `recursive` is set.
+///
+/// Failures on individual entries do not abort the scan: an unreadable file
+/// or subdirectory is reported with its error in the returned list.
+///
+/// # Arguments
+///
+/// * `path` - The directory to scan (accepts &str, String, Path, PathBuf, etc.)
+/// * `recursive` - Whether to also scan subdirectories
+///
+/// # Returns
+///
+/// A list of `(path, result)` pairs, or an I/O error if `path` itself cannot be read
+pub fn detect_dir<P: AsRef<Path>>(path: P, recursive: bool) -> io::Result<Vec<DirEntryResult>> {
+    let mut results = Vec::new();
+    scan_dir(path.as_ref(), recursive, &mut results)?;
+    Ok(results)
+}
+
+fn scan_dir(dir: &Path, recursive: bool, results: &mut Vec<DirEntryResult>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_file() => {
+                let detected = detect_file(&path);
+                results.push((path, detected));
+            }
+            Ok(file_type) if file_type.is_dir() && recursive => {
+                if let Err(error) = scan_dir(&path, recursive, results) {
+                    results.push((path, Err(error)));
+                }
+            }
+            Ok(_) => {}
+            Err(error) => results.push((path, Err(error))),
+        }
+    }
+    Ok(())
 }
 
 /// Checks if a MIME type equals any of the provided types.
@@ -300,6 +484,102 @@ pub fn register_extension(extension: &str, matcher: fn(&[u8]) -> bool) {
         .push(matcher);
 }
 
+/// User refinements keyed by the address of the tree node they refine
+static REFINEMENTS: LazyLock<RwLock<HashMap<usize, Vec<&'static MimeType>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Set once the first refinement is registered, so detection skips the lock otherwise
+static HAS_REFINEMENTS: AtomicBool = AtomicBool::new(false);
+
+/// Attaches a custom refinement below an existing MIME type in the detection tree.
+///
+/// Whenever detection reaches a node whose MIME type matches `parent_mime`,
+/// the refinement's matcher is checked before that node's built-in children,
+/// and the refined type is returned if it fires. The refined type inherits
+/// the parent's kind and reports the parent through `parent()`.
+///
+/// Refinements are kept for the lifetime of the program; their strings are
+/// leaked to obtain the `'static` lifetime detection results require.
+///
+/// # Arguments
+///
+/// * `parent_mime` - MIME type (or alias) of an existing built-in type or refinement
+/// * `child_mime` - MIME type reported when the refinement matches
+/// * `ext` - File extension of the refined type (e.g. `".dotx"`)
+/// * `kind` - Kind of the refined type, combined with the parent's kind
+/// * `matcher` - A function that takes byte data and returns true if it matches
+///
+/// # Returns
+///
+/// The refined type on success, or an `InvalidInput` error if no type matches `parent_mime`
+pub fn register_refinement(
+    parent_mime: &str,
+    child_mime: &str,
+    ext: &str,
+    kind: MimeKind,
+    matcher: fn(&[u8]) -> bool,
+) -> io::Result<&'static MimeType> {
+    ensure_init();
+
+    let mut refinements = REFINEMENTS
+        .write()
+        .expect("refinement registry lock poisoned");
+    let mut parents: Vec<&'static MimeType> = (0..=u16::MAX)
+        .map_while(MimeType::from_id)
+        .filter(|mime| mime.is(parent_mime))
+        .collect();
+    parents.extend(
+        refinements
+            .values()
+            .flatten()
+            .filter(|mime| mime.is(parent_mime)),
+    );
+    if parents.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unknown parent MIME type: {parent_mime}"),
+        ));
+    }
+
+    let child_mime: &'static str = Box::leak(child_mime.to_string().into_boxed_str());
+    let ext: &'static str = Box::leak(ext.to_string().into_boxed_str());
+
+    let mut first = None;
+    for parent in parents {
+        let refined: &'static MimeType = Box::leak(Box::new(
+            MimeType::new(child_mime, child_mime, ext, matcher, &[])
+                .with_kind(kind)
+                .with_parent(parent),
+        ));
+        refined.register();
+        refinements
+            .entry(parent as *const MimeType as usize)
+            .or_default()
+            .push(refined);
+        first.get_or_insert(refined);
+    }
+    HAS_REFINEMENTS.store(true, Ordering::Release);
+
+    Ok(first.expect("at least one parent"))
+}
+
+/// Returns the first refinement of `parent` matching `input`, if any
+pub(crate) fn match_refinement(
+    parent: &'static MimeType,
+    input: &[u8],
+) -> Option<&'static MimeType> {
+    if !HAS_REFINEMENTS.load(Ordering::Acquire) {
+        return None;
+    }
+    REFINEMENTS
+        .read()
+        .expect("refinement registry lock poisoned")
+        .get(&(parent as *const MimeType as usize))?
+        .iter()
+        .find(|refined| (refined.matcher)(input))
+        .copied()
+}
+
 /// Checks if a MIME type is supported by the library.
 ///
 /// Returns true if the MIME type has registered matchers.
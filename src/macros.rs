@@ -1,10 +1,14 @@
 /// Generates a prefix vector for O(1) lookup by first byte
 /// Uses provided bucket name mapping to generate statics with custom names
+///
+/// A bucket may cover several bytes (`0x09 | 0x20 => [...]`). With a leading
+/// `default => [...]` entry, every byte without its own bucket maps to the
+/// default bucket instead of an empty one (used for exhaustive child routing).
 macro_rules! build_prefix_vec {
     (
         $(#[$meta:meta])*
         $vis:vis static $name:ident: [
-            $( $byte:literal => [ $($types:expr),* $(,)? ] as $bucket_name:ident ),* $(,)?
+            $( $($byte:literal)|+ => [ $($types:expr),* $(,)? ] as $bucket_name:ident ),* $(,)?
         ]
     ) => {
         // Generate static slices for each bucket
@@ -17,8 +21,71 @@ macro_rules! build_prefix_vec {
         $vis static $name: [&[&MimeType]; 256] = {
             const EMPTY: &[&MimeType] = &[];
             let mut arr = [EMPTY; 256];
-            $(
+            $($(
                 arr[$byte] = $bucket_name;
+            )+)*
+            arr
+        };
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis static $name:ident: [
+            default => [ $($default_types:expr),* $(,)? ] as $default_name:ident,
+            $( $($byte:literal)|+ => [ $($types:expr),* $(,)? ] as $bucket_name:ident ),* $(,)?
+        ]
+    ) => {
+        // Generate static slices for each bucket
+        static $default_name: &[&MimeType] = &[$($default_types),*];
+        $(
+            static $bucket_name: &[&MimeType] = &[$($types),*];
+        )*
+
+        // Generate the array with documentation
+        $(#[$meta])*
+        $vis static $name: [&[&MimeType]; 256] = {
+            let mut arr = [$default_name; 256];
+            $($(
+                arr[$byte] = $bucket_name;
+            )+)*
+            arr
+        };
+    };
+}
+
+/// Generates a second-byte refinement for selected prefix vector buckets
+///
+/// Each first byte lists sub-buckets keyed by the second byte; byte pairs
+/// that are not listed fall back to the regular first-byte bucket.
+macro_rules! build_prefix_vec2 {
+    (
+        $(#[$meta:meta])*
+        $vis:vis static $name:ident: [
+            $( $first:literal => {
+                $( $($second:literal)|+ => [ $($types:expr),* $(,)? ] as $bucket_name:ident ),* $(,)?
+            } as $table_name:ident ),* $(,)?
+        ]
+    ) => {
+        // Generate static slices for each bucket and a table per first byte
+        $(
+            $(
+                static $bucket_name: &[&MimeType] = &[$($types),*];
+            )*
+            static $table_name: [Option<&[&MimeType]>; 256] = {
+                let mut arr: [Option<&[&MimeType]>; 256] = [None; 256];
+                $($(
+                    arr[$second] = Some($bucket_name);
+                )+)*
+                arr
+            };
+        )*
+
+        // Generate the array with documentation
+        $(#[$meta])*
+        $vis static $name: $crate::mime_type::SecondBytePrefixVec = {
+            let mut arr: $crate::mime_type::SecondBytePrefixVec = [None; 256];
+            $(
+                arr[$first] = Some(&$table_name);
             )*
             arr
         };
@@ -53,10 +120,11 @@ macro_rules! build_prefix_vec {
 /// - Offset with prefix: `offset(8, b"WEBP", prefix: b"RIFF")`
 macro_rules! mimetype {
     // Build function that actually creates the MimeType with all parameters
-    (@build $static_name:ident, $mime:expr, $name:expr, $ext:expr, $matcher:expr, $children:expr,
-     $kind:expr, $aliases:expr, $ext_aliases:expr, $parent:expr) => {
+    (@build $static_name:ident, $mime:expr, $name:expr, $ext:expr, $matcher:expr, $signature:expr,
+     $children:expr, $kind:expr, $aliases:expr, $ext_aliases:expr, $parent:expr) => {
         static $static_name: $crate::MimeType = {
-            let mut mime = $crate::MimeType::new($mime, $name, $ext, $matcher, $children);
+            let mut mime = $crate::MimeType::new($mime, $name, $ext, $matcher, $children)
+                .with_signature_description($signature);
             if let Some(k) = $kind {
                 mime = mime.with_kind(k);
             }
@@ -85,6 +153,7 @@ macro_rules! mimetype {
     ) => {
         mimetype!(@build $static_name, $mime, mimetype!(@opt_str $($name)?), $ext,
             |input| input.starts_with($prefix),
+            concat!("prefix ", stringify!($prefix)),
             &[$($($child),*)?],
             Some($crate::MimeKind::$kind),
             mimetype!(@opt_slice $($($alias),*)?),
@@ -100,6 +169,7 @@ macro_rules! mimetype {
                 const PREFIX: &[u8] = &[$($byte),+];
                 input.starts_with(PREFIX)
             },
+            concat!("prefix ", stringify!([$($byte),+])),
             &[],
             Some($crate::MimeKind::$kind), None, None, None
         );
@@ -116,6 +186,7 @@ macro_rules! mimetype {
                 const FIRST: &[u8] = &[$($first_byte),+];
                 input.starts_with(FIRST) $(|| input.starts_with(&[$($rest_byte),+]))+
             },
+            concat!("prefix ", stringify!([$($first_byte),+]) $(, " | ", stringify!([$($rest_byte),+]))+),
             &[],
             Some($crate::MimeKind::$kind), None, mimetype!(@opt_slice $($($ext_alias),*)?), None
         );
@@ -133,6 +204,7 @@ macro_rules! mimetype {
     ) => {
         mimetype!(@build $static_name, $mime, mimetype!(@opt_str $($name)?), $ext,
             |input| input.starts_with($first) $(|| input.starts_with($rest))+,
+            concat!("prefix ", stringify!($first) $(, " | ", stringify!($rest))+),
             &[$($($child),*)?],
             Some($crate::MimeKind::$kind),
             mimetype!(@opt_slice $($($alias),*)?),
@@ -169,6 +241,7 @@ macro_rules! mimetype {
                 let bytes: &[u8] = $bytes;
                 input.len() >= offset + bytes.len() && &input[offset..offset + bytes.len()] == bytes
             },
+            concat!("offset ", stringify!($offset), ": ", stringify!($bytes)),
             &[$($($child),*)?],
             Some($crate::MimeKind::$kind),
             mimetype!(@opt_slice $($($alias),*)?),
@@ -198,6 +271,10 @@ macro_rules! mimetype {
                     && input.len() >= offset + bytes.len()
                     && &input[offset..offset + bytes.len()] == bytes
             },
+            concat!(
+                "offset ", stringify!($offset), ": ", stringify!($bytes),
+                " after ", stringify!($prefix_bytes), " at offset ", stringify!($prefix_offset)
+            ),
             &[$($($child),*)?],
             Some($crate::MimeKind::$kind),
             mimetype!(@opt_slice $($($alias),*)?),
@@ -423,4 +500,25 @@ mod tests {
         assert!((TEST_RTF_PARENT.matcher)(b"{\\rtf0\\ansi"));
         assert!(TEST_RTF_PARENT.parent().is_some());
     }
+
+    #[test]
+    fn test_mimetype_signature_descriptions() {
+        assert_eq!(TEST_FLV.signature_description(), Some("prefix b\"FLV\""));
+        assert_eq!(
+            TEST_MULTI.signature_description(),
+            Some("prefix b\"GIF87a\" | b\"GIF89a\"")
+        );
+        assert_eq!(
+            TEST_TAR_FMT.signature_description(),
+            Some("offset 257: b\"ustar\"")
+        );
+        assert_eq!(
+            TEST_WAV_FMT.signature_description(),
+            Some("offset 8: b\"WAVE\" after b\"RIFF\" at offset 0")
+        );
+        assert!(TEST_PNG_ARR
+            .signature_description()
+            .is_some_and(|s| s.starts_with("prefix [0x89")));
+        assert_eq!(TEST_CHILD.signature_description(), None);
+    }
 }
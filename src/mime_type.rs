@@ -1,5 +1,19 @@
+use std::sync::LazyLock;
+
 use crate::{register_extension, register_mime, MimeKind};
 
+/// Second-byte refinement of a prefix vector: for selected first bytes, an
+/// optional bucket per second byte that replaces the first-byte bucket
+pub(crate) type SecondBytePrefixVec =
+    [Option<&'static [Option<&'static [&'static MimeType]>; 256]>; 256];
+
+/// Picks a child straight from the input, bypassing its siblings' matchers
+pub(crate) type ChildDispatch = fn(&[u8]) -> Option<&'static MimeType>;
+
+/// Children to try for each first input byte, built on first use with
+/// [`MimeType::merge_child_routes`]
+pub(crate) type ChildRoutes = LazyLock<[Box<[&'static MimeType]>; 256]>;
+
 pub struct MimeType {
     mime: &'static str,
     name: &'static str,
@@ -12,6 +26,14 @@ pub struct MimeType {
     kind: MimeKind,
     /// Optional prefix vector for optimized lookups (used only by ROOT)
     prefix_vec: Option<&'static [&'static [&'static MimeType]; 256]>,
+    /// Optional two-byte buckets for crowded prefix vector entries (used only by ROOT)
+    prefix_vec2: Option<&'static SecondBytePrefixVec>,
+    /// Optional exhaustive first-byte routing of `children` (used by UTF8)
+    child_routes: Option<&'static ChildRoutes>,
+    /// Optional direct pick of a child from the input (used by UTF8 for `#!` scripts)
+    child_dispatch: Option<ChildDispatch>,
+    /// Optional human-readable description of the magic signature
+    signature: Option<&'static str>,
 }
 
 impl MimeType {
@@ -33,6 +55,10 @@ impl MimeType {
             parent: None,
             kind: MimeKind::UNKNOWN,
             prefix_vec: None,
+            prefix_vec2: None,
+            child_routes: None,
+            child_dispatch: None,
+            signature: None,
         }
     }
 
@@ -67,6 +93,66 @@ impl MimeType {
         self
     }
 
+    /// Narrow crowded prefix vector buckets by the second input byte
+    ///
+    /// Each two-byte bucket must list, in first-byte bucket order, every
+    /// member of that bucket that can match input starting with both bytes.
+    /// Byte pairs without a bucket use the first-byte bucket.
+    pub const fn with_prefix_vec2(mut self, prefix_vec2: &'static SecondBytePrefixVec) -> Self {
+        self.prefix_vec2 = Some(prefix_vec2);
+        self
+    }
+
+    /// Route child lookups by the first input byte
+    ///
+    /// Unlike `with_prefix_vec`, each bucket replaces the linear scan: it must
+    /// list, in `children` order, every child that can match input starting
+    /// with that byte. Build the buckets with [`Self::merge_child_routes`].
+    /// Empty input still scans all children.
+    pub const fn with_child_routes(mut self, child_routes: &'static ChildRoutes) -> Self {
+        self.child_routes = Some(child_routes);
+        self
+    }
+
+    /// Builds the first-byte buckets for [`Self::with_child_routes`]
+    ///
+    /// Each bucket holds the children anchored at that byte plus the
+    /// `scanners`, which search the content and may match after any first
+    /// byte, in `children` order.
+    pub fn merge_child_routes(
+        &self,
+        anchored: &[&[&'static MimeType]; 256],
+        scanners: &[&'static MimeType],
+    ) -> [Box<[&'static MimeType]>; 256] {
+        let contains =
+            |list: &[&MimeType], child: &MimeType| list.iter().any(|m| std::ptr::eq(*m, child));
+        std::array::from_fn(|byte| {
+            self.children
+                .iter()
+                .filter(|child| contains(anchored[byte], child) || contains(scanners, child))
+                .copied()
+                .collect()
+        })
+    }
+
+    /// Pick a child straight from the input before any routing or scanning
+    ///
+    /// When `dispatch` returns a child, that child is taken without running
+    /// the matchers of its siblings; `None` falls back to the usual lookup.
+    pub const fn with_child_dispatch(mut self, dispatch: ChildDispatch) -> Self {
+        self.child_dispatch = Some(dispatch);
+        self
+    }
+
+    /// Attach a short description of the signature checked by the matcher
+    ///
+    /// Set automatically by the `mimetype!` macro; hand-written matchers can
+    /// use this to document what they look for (e.g. `"offset 8: WAVE after RIFF"`).
+    pub const fn with_signature_description(mut self, signature: &'static str) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
     pub fn register(&'static self) {
         register_mime(self.mime, self.matcher);
         if !self.extension.is_empty() {
@@ -139,11 +225,26 @@ impl MimeType {
     }
 
     pub fn match_bytes(&'static self, input: &[u8]) -> &'static MimeType {
+        // User refinements take priority over built-in children
+        if let Some(refined) = crate::match_refinement(self, input) {
+            return refined.match_bytes(input);
+        }
+
+        if let Some(child) = self.child_dispatch.and_then(|dispatch| dispatch(input)) {
+            return child.match_bytes(input);
+        }
+
         // Use prefix vector for O(1) lookup if available
         if let Some(prefix_vec) = self.prefix_vec {
             if !input.is_empty() {
                 let first_byte = input[0] as usize;
-                for child in prefix_vec[first_byte] {
+                let bucket = self
+                    .prefix_vec2
+                    .and_then(|prefix_vec2| prefix_vec2[first_byte])
+                    .zip(input.get(1))
+                    .and_then(|(second, &second_byte)| second[second_byte as usize])
+                    .unwrap_or(prefix_vec[first_byte]);
+                for child in bucket {
                     if (child.matcher)(input) {
                         return child.match_bytes(input);
                     }
@@ -152,7 +253,7 @@ impl MimeType {
         }
 
         // Linear search through remaining children
-        for child in self.children {
+        for child in self.candidates(input) {
             if (child.matcher)(input) {
                 return child.match_bytes(input);
             }
@@ -160,6 +261,14 @@ impl MimeType {
         self
     }
 
+    /// Children that need to be checked for `input`, in priority order
+    pub(crate) fn candidates(&self, input: &[u8]) -> &'static [&'static MimeType] {
+        match (self.child_routes, input.first()) {
+            (Some(routes), Some(&first)) => &routes[first as usize],
+            _ => self.children,
+        }
+    }
+
     pub fn flatten(&'static self) -> Vec<&'static MimeType> {
         let mut result = vec![self];
         for child in self.children {
@@ -175,6 +284,56 @@ impl MimeType {
     pub fn extension_aliases(&self) -> &'static [&'static str] {
         self.extension_aliases
     }
+
+    /// Get all file extensions: the primary extension first, then the aliases
+    ///
+    /// Empty entries (formats without an extension) are skipped.
+    pub fn extensions(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.extension)
+            .chain(self.extension_aliases.iter().copied())
+            .filter(|ext| !ext.is_empty())
+    }
+
+    /// Check whether `ext` is one of this type's extensions
+    ///
+    /// The comparison ignores ASCII case and a leading dot, so `"JPEG"`,
+    /// `".jpeg"` and `".JPEG"` all match `.jpeg`.
+    pub fn has_extension(&self, ext: &str) -> bool {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        !ext.is_empty()
+            && self.extensions().any(|candidate| {
+                candidate
+                    .strip_prefix('.')
+                    .unwrap_or(candidate)
+                    .eq_ignore_ascii_case(ext)
+            })
+    }
+
+    /// Get the stable numeric id of this MIME type
+    ///
+    /// Ids are assigned to every built-in format and never change between
+    /// releases, which makes them suitable for compact storage and FFI.
+    /// Returns `None` for MIME types defined outside this crate.
+    pub fn id(&'static self) -> Option<u16> {
+        crate::ensure_init();
+        crate::tree::id_of(self)
+    }
+
+    /// Look up a built-in MIME type by its stable numeric id
+    ///
+    /// Returns `None` for unassigned ids, including the range reserved for
+    /// custom types starting at [`CUSTOM_ID_START`](crate::CUSTOM_ID_START).
+    pub fn from_id(id: u16) -> Option<&'static MimeType> {
+        crate::tree::from_id(id)
+    }
+
+    /// Get the description of the magic signature, if one is known
+    ///
+    /// Intended for debugging and documentation tooling only; detection
+    /// never consults this value.
+    pub fn signature_description(&self) -> Option<&'static str> {
+        self.signature
+    }
 }
 
 impl std::fmt::Display for MimeType {
@@ -182,3 +341,231 @@ impl std::fmt::Display for MimeType {
         write!(f, "{}", self.mime)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MimeType;
+    use crate::tree::ROOT;
+
+    fn visit(mime: &'static MimeType, missing: &mut Vec<&'static str>) {
+        if mime.id().is_none() {
+            missing.push(mime.mime());
+        }
+        for child in mime.children {
+            visit(child, missing);
+        }
+        if let Some(prefix_vec) = mime.prefix_vec {
+            for bucket in prefix_vec.iter() {
+                for child in bucket.iter() {
+                    visit(child, missing);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_tree_node_has_id() {
+        let mut missing = Vec::new();
+        visit(&ROOT, &mut missing);
+        assert!(missing.is_empty(), "MIME types without id: {:?}", missing);
+    }
+
+    fn utf8() -> &'static MimeType {
+        let utf8 = crate::detect(b"plain text");
+        assert!(utf8.child_routes.is_some());
+        utf8
+    }
+
+    /// Reference lookup without first-byte routing
+    fn linear_match(mime: &'static MimeType, input: &[u8]) -> &'static MimeType {
+        if let Some(child) = mime.child_dispatch.and_then(|dispatch| dispatch(input)) {
+            return child;
+        }
+        mime.children
+            .iter()
+            .find(|child| (child.matcher)(input))
+            .map_or(mime, |child| child.match_bytes(input))
+    }
+
+    #[test]
+    fn test_child_routes_keep_children_order() {
+        let utf8 = utf8();
+        let routes = utf8.child_routes.unwrap();
+        let routed = |child: &&MimeType, bucket: &[&MimeType]| {
+            bucket.iter().any(|member| std::ptr::eq(*member, *child))
+        };
+        // No text format starts with `z`, so its bucket holds only the content scanners
+        let scanners = &routes[b'z' as usize];
+
+        for child in utf8.children {
+            assert!(
+                routes.iter().any(|bucket| routed(child, bucket)),
+                "{} is never routed",
+                child.mime()
+            );
+        }
+        for (byte, bucket) in routes.iter().enumerate() {
+            // Each bucket is the UTF8 children filtered to its members, in the same order
+            let expected: Vec<&str> = utf8
+                .children
+                .iter()
+                .filter(|child| routed(child, bucket))
+                .map(|child| child.mime())
+                .collect();
+            let actual: Vec<&str> = bucket.iter().map(|member| member.mime()).collect();
+            assert_eq!(actual, expected, "bucket 0x{byte:02x} out of order");
+            for scanner in scanners {
+                assert!(
+                    routed(scanner, bucket),
+                    "bucket 0x{byte:02x} misses {}",
+                    scanner.mime()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_child_routes_match_linear_scan() {
+        const SAMPLES: &[&[u8]] = &[
+            b"{\"name\": \"value\", \"list\": [1, 2, 3]}",
+            b"[1, 2, 3]",
+            b"[{\"a\":1}]",
+            b"\"text\"",
+            b"-1.5e3",
+            b"true",
+            b"null",
+            b"{\\rtf1\\ansi hello}",
+            b"<!DOCTYPE html><html><body></body></html>",
+            b"<?xml version=\"1.0\"?><root/>",
+            b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>",
+            b"<?php echo 'hi'; ?>",
+            b"#!/bin/bash\necho hi\n",
+            b"#!/usr/bin/env python3\nprint('hi')\n",
+            b"#define foo_width 8\n#define foo_height 8\n",
+            b"#include <stdio.h>\nint main(void) { return 0; }\n",
+            b"def main():\n    print('hi')\n\nif __name__ == '__main__':\n    main()\n",
+            b"package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(1)\n}\n",
+            b"fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}\n",
+            b"BEGIN:VCARD\r\nVERSION:3.0\r\nFN:John\r\nEND:VCARD\r\n",
+            b"BEGIN:VCALENDAR\r\nVERSION:2.0\r\nEND:VCALENDAR\r\n",
+            b"WEBVTT\n\n00:00.000 --> 00:01.000\nHi\n",
+            b"1\n00:00:01,000 --> 00:00:02,000\nHi\n",
+            b"WARC/1.0\r\nWARC-Type: warcinfo\r\n",
+            b"\\documentclass{article}\n",
+            b"@echo off\r\necho hi\r\n",
+            b"REM comment\r\n",
+            b"# OBJ\nv 0 0 0\nv 1 0 0\nv 1 1 0\nf 1 2 3\n",
+            b"@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:chr1\tLN:248956422\n",
+            b"@read1\nACGTACGTAC\n+\nIIIIIIIIII\n",
+            b">seq1 description\nACGTACGTACGTACGTACGT\n",
+            b"##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\n",
+            b"MSH|^~\\&|APP|FAC|||20240101||ADT^A01|1|P|2.5\r",
+            b"UNB+UNOA:3+SENDER+RECEIVER+240101:1200+1'",
+            b"From: a@example.com\nTo: b@example.com\nSubject: hi\n\nbody\n",
+            b"[server]\nhost = \"localhost\"\nport = 8080\n",
+            b"a,b,c\n1,2,3\n4,5,6\n",
+            b"a\tb\tc\n1\t2\t3\n",
+            b"Microsoft Visual Studio Solution File, Format Version 12.00\n",
+            b"[Script Info]\nScriptType: v4.00+\n\n[V4+ Styles]\n",
+            b"[ti:Song]\n[00:01.00]one\n[00:02.50]two\n",
+            b"TITLE \"Album\"\nFILE \"a.wav\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n",
+            b"REM GENRE Jazz\nFILE \"a.flac\" WAVE\n  TRACK 01 AUDIO\n",
+            b"plain text without any structure",
+        ];
+
+        let utf8 = utf8();
+        for sample in SAMPLES {
+            assert!(std::ptr::eq(
+                utf8.match_bytes(sample),
+                linear_match(utf8, sample)
+            ));
+            // Any leading byte must route to a bucket that gives the same result
+            for byte in 0..=255u8 {
+                let input = [&[byte][..], sample].concat();
+                assert!(
+                    std::ptr::eq(utf8.match_bytes(&input), linear_match(utf8, &input)),
+                    "routing changed the result for {:?}",
+                    String::from_utf8_lossy(&input)
+                );
+            }
+        }
+    }
+
+    /// Reference ROOT lookup using only the first-byte buckets
+    fn first_byte_match(mime: &'static MimeType, input: &[u8]) -> &'static MimeType {
+        let bucket = mime.prefix_vec.unwrap()[input[0] as usize];
+        bucket
+            .iter()
+            .chain(mime.children)
+            .find(|child| (child.matcher)(input))
+            .map_or(mime, |child| child.match_bytes(input))
+    }
+
+    #[test]
+    fn test_prefix_vec2_keeps_bucket_order() {
+        let prefix_vec = ROOT.prefix_vec.unwrap();
+        for (first, table) in ROOT.prefix_vec2.unwrap().iter().enumerate() {
+            for bucket in table.iter().flat_map(|table| table.iter().flatten()) {
+                let mut positions = bucket.iter().map(|narrowed| {
+                    prefix_vec[first]
+                        .iter()
+                        .position(|member| std::ptr::eq(*member, *narrowed))
+                        .unwrap_or_else(|| panic!("{} not in bucket", narrowed.mime()))
+                });
+                let mut last = positions.next();
+                for position in positions {
+                    assert!(last < Some(position), "bucket 0x{first:02x} out of order");
+                    last = Some(position);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefix_vec2_matches_first_byte_buckets() {
+        // Tails covering the signatures of the 0x4D and 0xFF bucket members
+        const TAILS: &[&[u8]] = &[
+            b"",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            b"\xff\xe0\x00\x10JFIF\x00\x01\x01\x00\x00\x01",
+            b"\xff\xf7\x00\x0b\x08\x00\x10",
+            b"\x0e\x53\x00\x6b\x00\x65\x00\x74\x00\x63\x00\x68\x00",
+            b"R\x00e\x00g\x00i\x00s\x00t\x00r\x00y\x00",
+            b"\x02\x00\x04\x04\x05\x54\x02\x00\x00\x00",
+            b"\x00*\x00\x00\x00\x08\x00\x00",
+            b"OR\x00\x00\x00\x08",
+            b"CF\x00\x00\x00\x00",
+            b"WIM\x00\x00\x00\xd0\x00\x00\x00",
+            b"\x90\x00\x03\x00\x00\x00\x04\x00\x00\x00\xff\xff",
+            b"\x4f\xff\x51\x00\x2f",
+            b"\x06\x00\x00sNaPpY",
+            b"\x0a\x00\x00\x00",
+            b"\x50\x80\x00\x00",
+            b"etric (key = \"distance\") {float {1.0}}\nGeometryNode {}\n",
+            b"\x3c\xb2\xa1\x02\x00\x04\x00",
+        ];
+
+        let prefix_vec2 = ROOT.prefix_vec2.unwrap();
+        for first in (0..=255u8).filter(|&b| prefix_vec2[b as usize].is_some()) {
+            for second in 0..=255u8 {
+                for tail in TAILS {
+                    let input = [&[first, second][..], tail].concat();
+                    assert!(
+                        std::ptr::eq(ROOT.match_bytes(&input), first_byte_match(&ROOT, &input)),
+                        "two-byte dispatch changed the result for {input:02x?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_child_routes_reduce_candidates_for_json() {
+        let utf8 = utf8();
+        let json = b"{\"name\": \"value\"}";
+        let candidates = utf8.candidates(json);
+        assert!(candidates.len() < utf8.children.len());
+        assert!(candidates.iter().any(|child| child.is("application/json")));
+        assert!(!candidates.iter().any(|child| child.is("text/html")));
+    }
+}
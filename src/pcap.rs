@@ -0,0 +1,68 @@
+//! Classic libpcap header inspection
+//!
+//! This module exposes the fields of a libpcap global header that are useful
+//! once a capture has been detected as `application/vnd.tcpdump.pcap`:
+//! byte order, timestamp resolution and the link-layer header type.
+
+/// Byte order of a capture file as indicated by its magic number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    /// Fields are stored most significant byte first
+    Big,
+    /// Fields are stored least significant byte first
+    Little,
+}
+
+/// Information extracted from a classic libpcap global header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcapInfo {
+    /// Byte order of all header and record fields
+    pub endianness: Endianness,
+    /// `true` when packet timestamps carry nanoseconds instead of microseconds
+    pub nanosecond: bool,
+    /// Link-layer header type (LINKTYPE_* value, e.g. 1 for Ethernet)
+    pub linktype: u32,
+}
+
+/// Size of the libpcap global header in bytes
+const PCAP_HEADER_LEN: usize = 24;
+
+/// Parses the global header of a classic libpcap capture.
+///
+/// Recognizes the microsecond (`0xA1B2C3D4`) and nanosecond (`0xA1B23C4D`)
+/// magic numbers in either byte order. The link-layer type is taken from the
+/// lower 28 bits of the last header field; the upper bits hold optional FCS
+/// information and are discarded.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice starting at the beginning of the capture file
+///
+/// # Returns
+///
+/// `Some(PcapInfo)` if `data` starts with a complete libpcap header, `None` otherwise
+pub fn pcap_info(data: &[u8]) -> Option<PcapInfo> {
+    if data.len() < PCAP_HEADER_LEN {
+        return None;
+    }
+
+    let (endianness, nanosecond) = match data[0..4] {
+        [0xA1, 0xB2, 0xC3, 0xD4] => (Endianness::Big, false),
+        [0xD4, 0xC3, 0xB2, 0xA1] => (Endianness::Little, false),
+        [0xA1, 0xB2, 0x3C, 0x4D] => (Endianness::Big, true),
+        [0x4D, 0x3C, 0xB2, 0xA1] => (Endianness::Little, true),
+        _ => return None,
+    };
+
+    let field = [data[20], data[21], data[22], data[23]];
+    let network = match endianness {
+        Endianness::Big => u32::from_be_bytes(field),
+        Endianness::Little => u32::from_le_bytes(field),
+    };
+
+    Some(PcapInfo {
+        endianness,
+        nanosecond,
+        linktype: network & 0x0FFF_FFFF,
+    })
+}
@@ -0,0 +1,77 @@
+//! One-call detection summaries
+//!
+//! [`DetectionReport`] collects everything the [`MimeType`] getters expose
+//! into a single owned value that is convenient to log, return from a
+//! service, or serialize (with the `serde` feature).
+
+use crate::{detect, MimeKind, MimeType};
+
+/// Summary of a detected format
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DetectionReport {
+    /// Full MIME type string, including parameters (e.g. `text/html; charset=utf-8`)
+    pub mime: &'static str,
+    /// Human-readable format name
+    pub name: &'static str,
+    /// Primary file extension (may be empty)
+    pub extension: &'static str,
+    /// Primary extension followed by all extension aliases
+    pub all_extensions: Vec<&'static str>,
+    /// Combined kind, including kinds inherited from parents
+    pub kind: MimeKind,
+    /// Alternative MIME types for the same format
+    pub aliases: &'static [&'static str],
+    /// MIME type of the container format, if any (e.g. ZIP for DOCX)
+    pub parent_mime: Option<&'static str>,
+    /// Value of the `charset` parameter of the MIME type, if present
+    pub charset: Option<&'static str>,
+    /// Value of the `version` parameter of the MIME type, if present (e.g. `4.0` for vCard 4.0)
+    pub version: Option<&'static str>,
+}
+
+impl DetectionReport {
+    /// Builds a report from a detected MIME type
+    pub fn new(mime: &'static MimeType) -> Self {
+        Self {
+            mime: mime.mime(),
+            name: mime.name(),
+            extension: mime.extension(),
+            all_extensions: mime.extensions().collect(),
+            kind: mime.kind(),
+            aliases: mime.aliases(),
+            parent_mime: mime.parent().map(|parent| parent.mime()),
+            charset: parameter(mime.mime(), "charset"),
+            version: parameter(mime.mime(), "version"),
+        }
+    }
+}
+
+impl From<&'static MimeType> for DetectionReport {
+    fn from(mime: &'static MimeType) -> Self {
+        Self::new(mime)
+    }
+}
+
+/// Extracts a parameter such as `charset` from a MIME type string
+fn parameter(mime: &'static str, name: &str) -> Option<&'static str> {
+    mime.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+    })
+}
+
+/// Detects the MIME type of the given byte data and summarizes it.
+///
+/// Equivalent to calling [`detect`] and reading every getter of the result.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing the data to analyze
+///
+/// # Returns
+///
+/// A [`DetectionReport`] describing the detected format
+pub fn detect_report(data: &[u8]) -> DetectionReport {
+    DetectionReport::new(detect(data))
+}
@@ -0,0 +1,102 @@
+//! Sparse data sources for file-based detection
+//!
+//! `detect()` only ever sees a contiguous prefix of the data. When detecting
+//! a real file we can do better: seek to the handful of windows where
+//! large-offset and trailer signatures live (ISO 9660 volume descriptors,
+//! Sega ROM headers, VHD footers, ...) and expose them through [`DataSource`]
+//! so matchers can address bytes by their absolute position in the file.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// A region of a file to load in addition to the detection head
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Window {
+    /// `len` bytes starting at an absolute offset
+    At(u64, usize),
+    /// The last `len` bytes of the file
+    Tail(usize),
+}
+
+/// Random access to (possibly partially loaded) data by absolute offset
+pub(crate) trait DataSource {
+    /// Total length of the underlying data, including unloaded regions
+    fn total_len(&self) -> u64;
+
+    /// Returns `len` bytes at `offset` if that range is available
+    fn read_at(&self, offset: u64, len: usize) -> Option<&[u8]>;
+
+    /// Checks whether `signature` is present at `offset`
+    fn has_at(&self, offset: u64, signature: &[u8]) -> bool {
+        self.read_at(offset, signature.len()) == Some(signature)
+    }
+}
+
+impl DataSource for [u8] {
+    fn total_len(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn read_at(&self, offset: u64, len: usize) -> Option<&[u8]> {
+        let start = usize::try_from(offset).ok()?;
+        self.get(start..start.checked_add(len)?)
+    }
+}
+
+/// A file view made of the detection head plus separately loaded windows
+pub(crate) struct SparseFile {
+    len: u64,
+    /// Loaded regions as (absolute offset, bytes); the head is always first
+    windows: Vec<(u64, Vec<u8>)>,
+}
+
+impl SparseFile {
+    /// Loads the given windows from `file`, reusing the already read head
+    ///
+    /// Windows entirely covered by the head are skipped and windows are
+    /// clamped to the file length, so small files cost no extra reads.
+    pub(crate) fn load(file: &mut File, head: Vec<u8>, windows: &[Window]) -> io::Result<Self> {
+        let len = file.metadata()?.len();
+        let head_len = head.len() as u64;
+        let mut loaded = vec![(0, head)];
+
+        for window in windows {
+            let (start, size) = match *window {
+                Window::At(offset, size) => (offset, size as u64),
+                Window::Tail(size) => (len.saturating_sub(size as u64), size as u64),
+            };
+            let end = start.saturating_add(size).min(len);
+            if end <= head_len || start >= end {
+                continue;
+            }
+
+            let mut buffer = Vec::with_capacity((end - start) as usize);
+            file.seek(SeekFrom::Start(start))?;
+            file.by_ref().take(end - start).read_to_end(&mut buffer)?;
+            loaded.push((start, buffer));
+        }
+
+        Ok(Self {
+            len,
+            windows: loaded,
+        })
+    }
+
+    /// Consumes the view, returning the detection head
+    pub(crate) fn into_head(mut self) -> Vec<u8> {
+        self.windows.swap_remove(0).1
+    }
+}
+
+impl DataSource for SparseFile {
+    fn total_len(&self) -> u64 {
+        self.len
+    }
+
+    fn read_at(&self, offset: u64, len: usize) -> Option<&[u8]> {
+        self.windows.iter().find_map(|(start, bytes)| {
+            let relative = offset.checked_sub(*start)?;
+            bytes.as_slice().read_at(relative, len)
+        })
+    }
+}
@@ -0,0 +1,127 @@
+//! C API Tests
+//!
+//! Exercises the `extern "C"` functions exposed by the `capi` feature
+//! directly from Rust, the same way a C caller would use them.
+
+#![cfg(feature = "capi")]
+
+use mimetype_detector::capi::{
+    mtd_detect, mtd_detect_file, mtd_extension, mtd_kind, mtd_mime_name, MTD_ERROR,
+};
+use mimetype_detector::{constants::*, register_refinement, MimeKind};
+use std::ffi::{CStr, CString};
+
+fn c_str(ptr: *const std::ffi::c_char) -> &'static str {
+    assert!(!ptr.is_null());
+    unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()
+}
+
+#[test]
+fn test_mtd_detect_png() {
+    let data = b"\x89PNG\r\n\x1a\n";
+    let id = unsafe { mtd_detect(data.as_ptr(), data.len()) };
+    assert!(id >= 0);
+    assert_eq!(c_str(mtd_mime_name(id)), IMAGE_PNG);
+    assert_eq!(c_str(mtd_extension(id)), ".png");
+    assert_eq!(mtd_kind(id), MimeKind::IMAGE.bits());
+}
+
+#[test]
+fn test_mtd_detect_null_and_empty() {
+    assert_eq!(unsafe { mtd_detect(std::ptr::null(), 4) }, MTD_ERROR);
+
+    let id = unsafe { mtd_detect(std::ptr::null(), 0) };
+    assert_eq!(c_str(mtd_mime_name(id)), APPLICATION_X_EMPTY);
+}
+
+#[test]
+fn test_mtd_unknown_id() {
+    assert!(mtd_mime_name(MTD_ERROR).is_null());
+    assert!(mtd_extension(i32::MAX).is_null());
+    assert_eq!(mtd_kind(MTD_ERROR), 0);
+}
+
+#[test]
+fn test_mtd_detect_file() {
+    let temp_path = "test_temp_capi.pdf";
+    std::fs::write(temp_path, b"%PDF-1.7\n").expect("Failed to write temp file");
+
+    let path = CString::new(temp_path).unwrap();
+    let id = unsafe { mtd_detect_file(path.as_ptr()) };
+    assert_eq!(c_str(mtd_mime_name(id)), APPLICATION_PDF);
+    assert!(MimeKind::DOCUMENT.bits() & mtd_kind(id) != 0);
+
+    std::fs::remove_file(temp_path).ok();
+
+    let missing = CString::new("this_file_does_not_exist_capi.bin").unwrap();
+    assert_eq!(unsafe { mtd_detect_file(missing.as_ptr()) }, MTD_ERROR);
+    assert_eq!(unsafe { mtd_detect_file(std::ptr::null()) }, MTD_ERROR);
+}
+
+#[test]
+fn test_mtd_refinement_without_id() {
+    let refined = register_refinement(
+        APPLICATION_PDF,
+        "application/x-capi-test-report",
+        ".ctr",
+        MimeKind::DOCUMENT,
+        |input| input.starts_with(b"%PDF-1.7\n%capi-test"),
+    )
+    .unwrap();
+    assert_eq!(refined.id(), None);
+
+    let data = b"%PDF-1.7\n%capi-test\n";
+    assert_eq!(unsafe { mtd_detect(data.as_ptr(), data.len()) }, MTD_ERROR);
+}
+
+/// Evaluates a `#define` value of the header: `(-1)` or `(1u << n)`
+fn header_value(value: &str) -> i64 {
+    let value = value.trim().trim_start_matches('(').trim_end_matches(')');
+    match value.split_once("u << ") {
+        Some((base, shift)) => base.parse::<i64>().unwrap() << shift.parse::<u32>().unwrap(),
+        None => value.parse().unwrap(),
+    }
+}
+
+#[test]
+fn test_header_constants_match_library() {
+    let header = include_str!("../include/mimetype_detector.h");
+    let defines: Vec<(&str, i64)> = header
+        .lines()
+        .filter_map(|line| line.strip_prefix("#define "))
+        .filter_map(|define| define.split_once(' '))
+        .map(|(name, value)| (name, header_value(value)))
+        .collect();
+
+    let expected: &[(&str, i64)] = &[
+        ("MTD_ERROR", i64::from(MTD_ERROR)),
+        ("MTD_KIND_ARCHIVE", MimeKind::ARCHIVE.bits().into()),
+        ("MTD_KIND_VIDEO", MimeKind::VIDEO.bits().into()),
+        ("MTD_KIND_AUDIO", MimeKind::AUDIO.bits().into()),
+        ("MTD_KIND_IMAGE", MimeKind::IMAGE.bits().into()),
+        ("MTD_KIND_DOCUMENT", MimeKind::DOCUMENT.bits().into()),
+        ("MTD_KIND_TEXT", MimeKind::TEXT.bits().into()),
+        ("MTD_KIND_FONT", MimeKind::FONT.bits().into()),
+        ("MTD_KIND_EXECUTABLE", MimeKind::EXECUTABLE.bits().into()),
+        ("MTD_KIND_APPLICATION", MimeKind::APPLICATION.bits().into()),
+        ("MTD_KIND_MODEL", MimeKind::MODEL.bits().into()),
+        ("MTD_KIND_DATABASE", MimeKind::DATABASE.bits().into()),
+        ("MTD_KIND_SPREADSHEET", MimeKind::SPREADSHEET.bits().into()),
+        (
+            "MTD_KIND_PRESENTATION",
+            MimeKind::PRESENTATION.bits().into(),
+        ),
+    ];
+    assert_eq!(defines, expected);
+
+    // Every exported function is declared
+    for function in [
+        "mtd_detect(",
+        "mtd_detect_file(",
+        "mtd_mime_name(",
+        "mtd_extension(",
+        "mtd_kind(",
+    ] {
+        assert!(header.contains(function), "{function} missing from header");
+    }
+}